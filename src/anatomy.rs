@@ -1,3 +1,5 @@
 pub mod builder;
+pub mod diff;
 pub(crate) mod errors;
+pub mod inventory;
 pub mod speaker;
@@ -2,6 +2,8 @@ use crate::anatomy::speaker::{
     AirflowControl, LarynxControl, LipControl, OralCavity, PulmonicControl, SpeakerAnatomy,
     TeethConfiguration, TongueControl, TonguePartControl, VelicPortControl, VoicingControl,
 };
+use crate::validation::{ValidationError, ValidationErrors};
+use rand::Rng;
 
 impl SpeakerAnatomy {
     /// Erstellt einen neuen Builder mit Standardwerten für einen menschlichen Sprecher.
@@ -166,8 +168,191 @@ impl SpeakerAnatomy {
     }
 }
 
+impl SpeakerAnatomy {
+    /// Würfelt eine zufällige Anatomie aus und repariert anschließend alle
+    /// Inkonsistenzen, die `validate_anatomical_consistency` melden würde.
+    /// Das Ergebnis besteht die Validierung immer.
+    pub fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        let teeth = [
+            TeethConfiguration::None,
+            TeethConfiguration::Fangs,
+            TeethConfiguration::Flat,
+            TeethConfiguration::Human,
+        ];
+        let lips = [LipControl::None, LipControl::Rigid, LipControl::Flexible];
+        let part = [
+            TonguePartControl::None,
+            TonguePartControl::Limited,
+            TonguePartControl::Agile,
+        ];
+        let voicing = [
+            VoicingControl::None,
+            VoicingControl::Basic,
+            VoicingControl::Advanced,
+        ];
+        let pulmonic = [
+            PulmonicControl::None,
+            PulmonicControl::Basic,
+            PulmonicControl::Advanced,
+        ];
+        let velic_port = [VelicPortControl::None, VelicPortControl::Controllable];
+
+        let mut anatomy = Self {
+            oral_cavity: OralCavity {
+                teeth: pick(rng, &teeth),
+                has_alveolar_ridge: rng.gen_bool(0.5),
+                has_hard_palate: rng.gen_bool(0.5),
+                has_soft_palate: rng.gen_bool(0.5),
+                has_uvula: rng.gen_bool(0.5),
+                has_epiglottis: rng.gen_bool(0.5),
+            },
+            lips: pick(rng, &lips),
+            tongue: TongueControl {
+                tip: pick(rng, &part),
+                blade: pick(rng, &part),
+                body: pick(rng, &part),
+                root: pick(rng, &part),
+                can_curl_for_retroflex: rng.gen_bool(0.5),
+                can_perform_lateral_release: rng.gen_bool(0.5),
+            },
+            larynx: LarynxControl {
+                voicing: pick(rng, &voicing),
+                can_produce_ejectives: rng.gen_bool(0.5),
+            },
+            airflow: AirflowControl {
+                pulmonic: pick(rng, &pulmonic),
+                velic_port: pick(rng, &velic_port),
+                can_produce_clicks: rng.gen_bool(0.5),
+            },
+        };
+        anatomy.repair();
+        anatomy
+    }
+
+    /// Behebt jede Regelverletzung aus `validate_anatomical_consistency`,
+    /// bevorzugt durch Hinzufügen von Strukturen bzw. Wegnehmen von Fähigkeiten
+    fn repair(&mut self) {
+        let cavity = &mut self.oral_cavity;
+        let tongue = &mut self.tongue;
+
+        // Ohne Lippen braucht es mindestens einen Zungenteil
+        if self.lips == LipControl::None
+            && tongue.tip == TonguePartControl::None
+            && tongue.blade == TonguePartControl::None
+            && tongue.body == TonguePartControl::None
+        {
+            tongue.body = TonguePartControl::Limited;
+        }
+
+        // Gaumen: Uvula und Velic Port setzen einen weichen, dieser einen harten Gaumen voraus
+        if cavity.has_uvula || self.airflow.velic_port == VelicPortControl::Controllable {
+            cavity.has_soft_palate = true;
+        }
+        if cavity.has_soft_palate {
+            cavity.has_hard_palate = true;
+        }
+
+        // Zunge
+        if tongue.tip != TonguePartControl::Agile {
+            tongue.can_curl_for_retroflex = false;
+        }
+        if tongue.tip == TonguePartControl::None && tongue.blade == TonguePartControl::None {
+            tongue.can_perform_lateral_release = false;
+            cavity.has_alveolar_ridge = false;
+        }
+
+        // Lippen und Zähne
+        if self.lips == LipControl::Flexible && cavity.teeth == TeethConfiguration::None {
+            self.lips = LipControl::Rigid;
+        }
+        if cavity.teeth == TeethConfiguration::Fangs && tongue.tip == TonguePartControl::Agile {
+            cavity.teeth = TeethConfiguration::Flat;
+        }
+        if self.lips == LipControl::None && cavity.teeth == TeethConfiguration::Human {
+            cavity.teeth = TeethConfiguration::Flat;
+        }
+
+        // Kehlkopf und Luftstrom
+        if self.larynx.voicing == VoicingControl::None {
+            self.larynx.can_produce_ejectives = false;
+        }
+        if tongue.tip == TonguePartControl::None
+            || tongue.blade == TonguePartControl::None
+            || tongue.body == TonguePartControl::None
+            || self.airflow.pulmonic == PulmonicControl::None
+        {
+            self.airflow.can_produce_clicks = false;
+        }
+    }
+}
+
+impl SpeakerAnatomy {
+    /// Serialisiert die Anatomie als JSON (z.B. für Spielstände)
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Lädt eine Anatomie aus JSON und prüft sie mit
+    /// `validate_anatomical_consistency`; inkonsistente Anatomien werden abgelehnt.
+    pub fn from_json(json: &str) -> Result<Self, ValidationErrors> {
+        let anatomy: SpeakerAnatomy = serde_json::from_str(json).map_err(|e| {
+            let mut errors = ValidationErrors::new();
+            errors.add(
+                "anatomy_json_parse_error",
+                ValidationError::new("anatomy_json_parse_error")
+                    .add_param("reason", e.to_string())
+                    .with_message(format!("Anatomie-JSON konnte nicht gelesen werden: {}", e)),
+            );
+            errors
+        })?;
+
+        anatomy.validate_anatomical_consistency()?;
+        Ok(anatomy)
+    }
+}
+
+fn pick<R: Rng + ?Sized, T: Clone>(rng: &mut R, options: &[T]) -> T {
+    options[rng.gen_range(0..options.len())].clone()
+}
+
 impl Default for SpeakerAnatomy {
     fn default() -> Self {
         Self::human()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_random_anatomies_are_consistent() {
+        let mut rng = ChaCha8Rng::seed_from_u64(42);
+        for _ in 0..100 {
+            let anatomy = SpeakerAnatomy::random(&mut rng);
+            assert!(
+                anatomy.validate_anatomical_consistency().is_ok(),
+                "{:?}",
+                anatomy
+            );
+        }
+    }
+
+    #[test]
+    fn test_json_round_trip_and_rejection() {
+        let human = SpeakerAnatomy::human();
+        assert_eq!(
+            SpeakerAnatomy::from_json(&human.to_json().unwrap()).unwrap(),
+            human
+        );
+
+        let inconsistent = SpeakerAnatomy::human().oral_cavity_has_soft_palate(false);
+        let errors = SpeakerAnatomy::from_json(&inconsistent.to_json().unwrap()).unwrap_err();
+        assert!(errors.has_error("uvula_requires_soft_palate"));
+
+        let errors = SpeakerAnatomy::from_json("{").unwrap_err();
+        assert!(errors.has_error("anatomy_json_parse_error"));
+    }
+}
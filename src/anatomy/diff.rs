@@ -0,0 +1,146 @@
+//! Comparison of two anatomy configurations
+
+use crate::anatomy::speaker::SpeakerAnatomy;
+use crate::phonology::phonemes::Consonant;
+use serde_json::Value;
+use std::fmt;
+
+/// One field that differs between two anatomies, e.g. `airflow.can_produce_clicks`
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnatomyFieldDiff {
+    /// Dotted path of the field
+    pub field: String,
+    pub old: String,
+    pub new: String,
+}
+
+/// All differences between two anatomies and their effect on producibility
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnatomyDiff {
+    pub fields: Vec<AnatomyFieldDiff>,
+    /// Consonants only the new anatomy can produce
+    pub gained: Vec<&'static Consonant>,
+    /// Consonants only the old anatomy can produce
+    pub lost: Vec<&'static Consonant>,
+}
+
+impl AnatomyDiff {
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+}
+
+impl SpeakerAnatomy {
+    /// Lists every field that changed from `self` to `other`, plus the
+    /// consonants that became producible or impossible
+    pub fn diff(&self, other: &Self) -> AnatomyDiff {
+        let mut fields = Vec::new();
+        if let (Ok(old), Ok(new)) = (serde_json::to_value(self), serde_json::to_value(other)) {
+            collect_diffs("", &old, &new, &mut fields);
+        }
+
+        let before = self.producible_phonemes();
+        let after = other.producible_phonemes();
+
+        AnatomyDiff {
+            fields,
+            gained: after
+                .iter()
+                .filter(|c| !before.contains(c))
+                .copied()
+                .collect(),
+            lost: before
+                .iter()
+                .filter(|c| !after.contains(c))
+                .copied()
+                .collect(),
+        }
+    }
+}
+
+fn collect_diffs(path: &str, old: &Value, new: &Value, diffs: &mut Vec<AnatomyFieldDiff>) {
+    match (old, new) {
+        (Value::Object(old_fields), Value::Object(new_fields)) => {
+            for (key, old_value) in old_fields {
+                let field = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", path, key)
+                };
+                if let Some(new_value) = new_fields.get(key) {
+                    collect_diffs(&field, old_value, new_value, diffs);
+                }
+            }
+        }
+        _ if old != new => diffs.push(AnatomyFieldDiff {
+            field: path.to_string(),
+            old: display_value(old),
+            new: display_value(new),
+        }),
+        _ => {}
+    }
+}
+
+fn display_value(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+impl fmt::Display for AnatomyDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for diff in &self.fields {
+            writeln!(f, "{}: {} → {}", diff.field, diff.old, diff.new)?;
+        }
+        for consonant in &self.gained {
+            writeln!(f, "+ [{}]", consonant.ipa)?;
+        }
+        for consonant in &self.lost {
+            writeln!(f, "- [{}]", consonant.ipa)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::anatomy::speaker::{LipControl, TeethConfiguration};
+    use crate::phonology::consonants::pulmonics::{B, F, M_BILABIAL, P};
+
+    #[test]
+    fn test_diff_reports_fields_and_producibility() {
+        let human = SpeakerAnatomy::human();
+        assert!(human.diff(&human).is_empty());
+
+        // There are no click statics yet, so flipping can_produce_clicks only
+        // shows up as a field diff; gained clicks cannot be checked until
+        // click phonemes exist
+        let no_clicks = SpeakerAnatomy::human().airflow_can_produce_clicks(false);
+        let diff = no_clicks.diff(&human);
+        assert_eq!(
+            diff.fields,
+            vec![AnatomyFieldDiff {
+                field: "airflow.can_produce_clicks".to_string(),
+                old: "false".to_string(),
+                new: "true".to_string(),
+            }]
+        );
+
+        let lipless = SpeakerAnatomy::human().lips(LipControl::None);
+        let diff = lipless.diff(&human);
+        for bilabial in [&P, &B, &M_BILABIAL] {
+            assert!(diff.gained.contains(&bilabial));
+        }
+        assert!(diff.lost.is_empty());
+        assert_eq!(human.diff(&lipless).lost, diff.gained);
+        assert!(diff.to_string().contains("+ [p]"));
+
+        let toothless = SpeakerAnatomy::human().teeth(TeethConfiguration::None);
+        let diff = human.diff(&toothless);
+        assert!(diff.lost.contains(&&F));
+        assert!(diff.to_string().contains("oral_cavity.teeth: Human → None"));
+        assert!(diff.to_string().contains("- [f]"));
+    }
+}
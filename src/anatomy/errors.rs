@@ -10,7 +10,9 @@ use crate::anatomy::speaker::{
     LipControl, PulmonicControl, SpeakerAnatomy, TeethConfiguration, TonguePartControl,
     VelicPortControl, VoicingControl,
 };
+use crate::phonology::{Diacritics, TongueHeight, TonguePosition};
 use crate::validation::errors::{ValidationError, ValidationErrors};
+use crate::validation::locale::anatomical_impossibility_reason;
 impl SpeakerAnatomy {
     /// Validates the anatomical consistency of a SpeakerAnatomy configuration
     ///
@@ -281,6 +283,18 @@ impl SpeakerAnatomy {
             ))
     }
 
+    /// Like `phoneme_anatomically_impossible`, but for a known `reason_key`
+    /// from the message catalog so the error can be localized
+    pub fn phoneme_anatomically_impossible_with_reason(
+        &self,
+        phoneme: &str,
+        reason_key: &str,
+    ) -> ValidationError {
+        let reason = anatomical_impossibility_reason(reason_key).unwrap_or(reason_key);
+        self.phoneme_anatomically_impossible(phoneme, reason)
+            .add_param("reason", reason_key)
+    }
+
     /// Validates airflow mechanism requirements (validation.txt lines 2-14)
     pub fn validate_airflow_mechanism(
         &self,
@@ -291,36 +305,34 @@ impl SpeakerAnatomy {
         match airflow_type {
             "pulmonic" => {
                 if matches!(self.airflow.pulmonic, PulmonicControl::None) {
-                    return Err(self.phoneme_anatomically_impossible(
+                    return Err(self.phoneme_anatomically_impossible_with_reason(
                         phoneme_ipa,
-                        "Pulmonische Laute erfordern airflow.pulmonic ≠ None",
+                        "pulmonic_requires_airflow",
                     ));
                 }
             }
             "click" => {
                 if !self.airflow.can_produce_clicks {
-                    return Err(self.phoneme_anatomically_impossible(
+                    return Err(self.phoneme_anatomically_impossible_with_reason(
                         phoneme_ipa,
-                        "Klicklaute erfordern airflow.can_produce_clicks = true",
+                        "clicks_require_click_airflow",
                     ));
                 }
                 // Additional check from validation.txt line 9: tongue.tip + tongue.body = Agile
                 if !matches!(self.tongue.tip, TonguePartControl::Agile)
                     || !matches!(self.tongue.body, TonguePartControl::Agile)
                 {
-                    return Err(self.phoneme_anatomically_impossible(
+                    return Err(self.phoneme_anatomically_impossible_with_reason(
                         phoneme_ipa,
-                        "Klicklaute erfordern agile Zungenspitze UND Zungenkörper",
+                        "clicks_require_agile_tongue",
                     ));
                 }
             }
-            "ejective" => {
-                if !self.larynx.can_produce_ejectives {
-                    return Err(self.phoneme_anatomically_impossible(
-                        phoneme_ipa,
-                        "Ejektive erfordern larynx.can_produce_ejectives = true",
-                    ));
-                }
+            "ejective" if !self.larynx.can_produce_ejectives => {
+                return Err(self.phoneme_anatomically_impossible_with_reason(
+                    phoneme_ipa,
+                    "ejectives_require_larynx",
+                ));
             }
             _ => {} // Unknown airflow types pass through
         }
@@ -339,42 +351,42 @@ impl SpeakerAnatomy {
             "bilabial" => {
                 // Lines 18-21: [p, b, m, w] require lips ≠ None
                 if matches!(self.lips, LipControl::None) {
-                    return Err(self.phoneme_anatomically_impossible(
+                    return Err(self.phoneme_anatomically_impossible_with_reason(
                         phoneme_ipa,
-                        "Bilabiale Laute [p, b, m, w] erfordern lips ≠ None",
+                        "bilabial_requires_lips",
                     ));
                 }
             }
             "labiodental" => {
                 // Lines 23-26: [f, v] require lips = Flexible AND teeth ∈ {Human, Flat}
                 if !matches!(self.lips, LipControl::Flexible) {
-                    return Err(self.phoneme_anatomically_impossible(
+                    return Err(self.phoneme_anatomically_impossible_with_reason(
                         phoneme_ipa,
-                        "Labiodentale Laute [f, v] erfordern lips = Flexible",
+                        "labiodental_requires_flexible_lips",
                     ));
                 }
                 if matches!(
                     self.oral_cavity.teeth,
                     TeethConfiguration::None | TeethConfiguration::Fangs
                 ) {
-                    return Err(self.phoneme_anatomically_impossible(
+                    return Err(self.phoneme_anatomically_impossible_with_reason(
                         phoneme_ipa,
-                        "Labiodentale Laute [f, v] erfordern teeth ∈ {Human, Flat}. Fangs/None → UNMÖGLICH"
+                        "labiodental_requires_teeth",
                     ));
                 }
             }
             "linguolabial" => {
                 // Lines 28-31: [t̼, d̼] (RARE) require lips = Flexible AND tongue.tip = Agile
                 if !matches!(self.lips, LipControl::Flexible) {
-                    return Err(self.phoneme_anatomically_impossible(
+                    return Err(self.phoneme_anatomically_impossible_with_reason(
                         phoneme_ipa,
-                        "Linguolabiale Laute [t̼, d̼] erfordern lips = Flexible",
+                        "linguolabial_requires_flexible_lips",
                     ));
                 }
                 if !matches!(self.tongue.tip, TonguePartControl::Agile) {
-                    return Err(self.phoneme_anatomically_impossible(
+                    return Err(self.phoneme_anatomically_impossible_with_reason(
                         phoneme_ipa,
-                        "Linguolabiale Laute [t̼, d̼] erfordern tongue.tip = Agile",
+                        "linguolabial_requires_agile_tip",
                     ));
                 }
             }
@@ -395,86 +407,86 @@ impl SpeakerAnatomy {
             "dental" => {
                 // Lines 34-37: [θ, ð] require teeth ≠ None AND tongue.tip/blade ≠ None
                 if matches!(self.oral_cavity.teeth, TeethConfiguration::None) {
-                    return Err(self.phoneme_anatomically_impossible(
+                    return Err(self.phoneme_anatomically_impossible_with_reason(
                         phoneme_ipa,
-                        "Dentale Laute [θ, ð] erfordern teeth ≠ None",
+                        "dental_requires_teeth",
                     ));
                 }
                 if matches!(self.tongue.tip, TonguePartControl::None)
                     && matches!(self.tongue.blade, TonguePartControl::None)
                 {
-                    return Err(self.phoneme_anatomically_impossible(
+                    return Err(self.phoneme_anatomically_impossible_with_reason(
                         phoneme_ipa,
-                        "Dentale Laute [θ, ð] erfordern tongue.tip/blade ≠ None",
+                        "dental_requires_tongue_front",
                     ));
                 }
             }
             "alveolar" => {
                 // Lines 39-43: [t, d, n, s, z, l, r] require alveolar_ridge = true AND tongue.tip/blade ≠ None
                 if !self.oral_cavity.has_alveolar_ridge {
-                    return Err(self.phoneme_anatomically_impossible(
+                    return Err(self.phoneme_anatomically_impossible_with_reason(
                         phoneme_ipa,
-                        "Alveolare Laute [t, d, n, s, z, l, r] erfordern has_alveolar_ridge = true",
+                        "alveolar_requires_ridge",
                     ));
                 }
                 if matches!(self.tongue.tip, TonguePartControl::None)
                     && matches!(self.tongue.blade, TonguePartControl::None)
                 {
-                    return Err(self.phoneme_anatomically_impossible(
+                    return Err(self.phoneme_anatomically_impossible_with_reason(
                         phoneme_ipa,
-                        "Alveolare Laute erfordern tongue.tip/blade ≠ None",
+                        "alveolar_requires_tongue_front",
                     ));
                 }
                 // Line 42: Laterals [l] require can_perform_lateral_release = true
                 if phoneme_ipa.contains('l') && !self.tongue.can_perform_lateral_release {
-                    return Err(self.phoneme_anatomically_impossible(
+                    return Err(self.phoneme_anatomically_impossible_with_reason(
                         phoneme_ipa,
-                        "Laterale [l] erfordern tongue.can_perform_lateral_release = true",
+                        "lateral_requires_lateral_release",
                     ));
                 }
             }
             "postalveolar" => {
                 // Lines 46-49: [ʃ, ʒ, tʃ, dʒ] require alveolar_ridge + hard_palate + tongue.blade = Agile
                 if !self.oral_cavity.has_alveolar_ridge || !self.oral_cavity.has_hard_palate {
-                    return Err(self.phoneme_anatomically_impossible(
+                    return Err(self.phoneme_anatomically_impossible_with_reason(
                         phoneme_ipa,
-                        "Postalveolare [ʃ, ʒ, tʃ, dʒ] erfordern has_alveolar_ridge = true UND has_hard_palate = true"
+                        "postalveolar_requires_ridge_and_palate",
                     ));
                 }
                 if !matches!(self.tongue.blade, TonguePartControl::Agile) {
-                    return Err(self.phoneme_anatomically_impossible(
+                    return Err(self.phoneme_anatomically_impossible_with_reason(
                         phoneme_ipa,
-                        "Postalveolare erfordern tongue.blade = Agile",
+                        "postalveolar_requires_agile_blade",
                     ));
                 }
             }
             "retroflex" => {
                 // Lines 55-58: [ʈ, ɖ, ɳ, ʂ, ʐ] require can_curl_for_retroflex = true AND tongue.tip = Agile
                 if !self.tongue.can_curl_for_retroflex {
-                    return Err(self.phoneme_anatomically_impossible(
+                    return Err(self.phoneme_anatomically_impossible_with_reason(
                         phoneme_ipa,
-                        "Retroflexe [ʈ, ɖ, ɳ, ʂ, ʐ] erfordern tongue.can_curl_for_retroflex = true",
+                        "retroflex_requires_curl",
                     ));
                 }
                 if !matches!(self.tongue.tip, TonguePartControl::Agile) {
-                    return Err(self.phoneme_anatomically_impossible(
+                    return Err(self.phoneme_anatomically_impossible_with_reason(
                         phoneme_ipa,
-                        "Retroflexe erfordern tongue.tip = Agile",
+                        "retroflex_requires_agile_tip",
                     ));
                 }
             }
             "palatal" => {
                 // Lines 60-63: [c, ɟ, ɲ, j] require hard_palate = true AND tongue.body = Agile
                 if !self.oral_cavity.has_hard_palate {
-                    return Err(self.phoneme_anatomically_impossible(
+                    return Err(self.phoneme_anatomically_impossible_with_reason(
                         phoneme_ipa,
-                        "Palatale [c, ɟ, ɲ, j] erfordern has_hard_palate = true",
+                        "palatal_requires_hard_palate",
                     ));
                 }
                 if !matches!(self.tongue.body, TonguePartControl::Agile) {
-                    return Err(self.phoneme_anatomically_impossible(
+                    return Err(self.phoneme_anatomically_impossible_with_reason(
                         phoneme_ipa,
-                        "Palatale erfordern tongue.body = Agile",
+                        "palatal_requires_agile_body",
                     ));
                 }
             }
@@ -495,30 +507,30 @@ impl SpeakerAnatomy {
             "velar" => {
                 // Lines 66-69: [k, g, ŋ, x, ɣ] require soft_palate = true AND tongue.body = Agile
                 if !self.oral_cavity.has_soft_palate {
-                    return Err(self.phoneme_anatomically_impossible(
+                    return Err(self.phoneme_anatomically_impossible_with_reason(
                         phoneme_ipa,
-                        "Velare [k, g, ŋ, x, ɣ] erfordern has_soft_palate = true",
+                        "velar_requires_soft_palate",
                     ));
                 }
                 if !matches!(self.tongue.body, TonguePartControl::Agile) {
-                    return Err(self.phoneme_anatomically_impossible(
+                    return Err(self.phoneme_anatomically_impossible_with_reason(
                         phoneme_ipa,
-                        "Velare erfordern tongue.body = Agile",
+                        "velar_requires_agile_body",
                     ));
                 }
             }
             "uvular" => {
                 // Lines 71-74: [q, ɢ, ɴ, χ, ʁ] require uvula = true AND tongue.body = Agile
                 if !self.oral_cavity.has_uvula {
-                    return Err(self.phoneme_anatomically_impossible(
+                    return Err(self.phoneme_anatomically_impossible_with_reason(
                         phoneme_ipa,
-                        "Uvulare [q, ɢ, ɴ, χ, ʁ] erfordern has_uvula = true",
+                        "uvular_requires_uvula",
                     ));
                 }
                 if !matches!(self.tongue.body, TonguePartControl::Agile) {
-                    return Err(self.phoneme_anatomically_impossible(
+                    return Err(self.phoneme_anatomically_impossible_with_reason(
                         phoneme_ipa,
-                        "Uvulare erfordern tongue.body = Agile",
+                        "uvular_requires_agile_body",
                     ));
                 }
             }
@@ -539,44 +551,42 @@ impl SpeakerAnatomy {
             "voiceless" => {
                 // Line 91: Basic requirement larynx.voicing ≠ None
                 if matches!(self.larynx.voicing, VoicingControl::None) {
-                    return Err(self.phoneme_anatomically_impossible(
+                    return Err(self.phoneme_anatomically_impossible_with_reason(
                         phoneme_ipa,
-                        "Stimmlose Laute erfordern larynx.voicing ≠ None",
+                        "voiceless_requires_voicing_control",
                     ));
                 }
             }
             "voiced" => {
                 // Lines 94-96: larynx.voicing ∈ {Basic, Advanced}
                 if matches!(self.larynx.voicing, VoicingControl::None) {
-                    return Err(self.phoneme_anatomically_impossible(
+                    return Err(self.phoneme_anatomically_impossible_with_reason(
                         phoneme_ipa,
-                        "Stimmhafte Laute erfordern larynx.voicing ∈ {Basic, Advanced}",
+                        "voiced_requires_voicing_control",
                     ));
                 }
             }
             "aspirated" => {
                 // Lines 98-100: [pʰ, tʰ, kʰ] require Advanced voicing AND pulmonic
                 if !matches!(self.larynx.voicing, VoicingControl::Advanced) {
-                    return Err(self.phoneme_anatomically_impossible(
+                    return Err(self.phoneme_anatomically_impossible_with_reason(
                         phoneme_ipa,
-                        "Aspirierte Laute [pʰ, tʰ, kʰ] erfordern larynx.voicing = Advanced",
+                        "aspirated_requires_advanced_voicing",
                     ));
                 }
                 if !matches!(self.airflow.pulmonic, PulmonicControl::Advanced) {
-                    return Err(self.phoneme_anatomically_impossible(
+                    return Err(self.phoneme_anatomically_impossible_with_reason(
                         phoneme_ipa,
-                        "Aspirierte Laute erfordern airflow.pulmonic = Advanced",
+                        "aspirated_requires_advanced_pulmonic",
                     ));
                 }
             }
-            "ejective" => {
-                // Lines 102-104: Already handled in validate_airflow_mechanism
-                if !self.larynx.can_produce_ejectives {
-                    return Err(self.phoneme_anatomically_impossible(
-                        phoneme_ipa,
-                        "Ejektive [p', t', k'] erfordern larynx.can_produce_ejectives = true",
-                    ));
-                }
+            // Lines 102-104: Already handled in validate_airflow_mechanism
+            "ejective" if !self.larynx.can_produce_ejectives => {
+                return Err(self.phoneme_anatomically_impossible_with_reason(
+                    phoneme_ipa,
+                    "ejective_voicing_requires_larynx",
+                ));
             }
             _ => {}
         }
@@ -594,18 +604,65 @@ impl SpeakerAnatomy {
         if is_nasal {
             // Lines 111-114: Nasal [m, n, ŋ, ɲ] require velic_port = Controllable AND soft_palate = true
             if !matches!(self.airflow.velic_port, VelicPortControl::Controllable) {
-                return Err(self.phoneme_anatomically_impossible(
+                return Err(self.phoneme_anatomically_impossible_with_reason(
                     phoneme_ipa,
-                    "Nasale [m, n, ŋ, ɲ] erfordern airflow.velic_port = Controllable",
+                    "nasal_requires_velic_port",
                 ));
             }
             if !self.oral_cavity.has_soft_palate {
-                return Err(self.phoneme_anatomically_impossible(
+                return Err(self.phoneme_anatomically_impossible_with_reason(
+                    phoneme_ipa,
+                    "nasal_requires_soft_palate",
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Validates vowel articulation: back and close vowels need a controllable
+    /// tongue body, rounded vowels need lips
+    pub fn validate_vowel_articulation(
+        &self,
+        phoneme_ipa: &str,
+        height: TongueHeight,
+        backness: TonguePosition,
+        rounded: bool,
+    ) -> Result<(), ValidationError> {
+        if matches!(self.tongue.body, TonguePartControl::None) {
+            if matches!(backness, TonguePosition::Back) {
+                return Err(self.phoneme_anatomically_impossible_with_reason(
+                    phoneme_ipa,
+                    "back_vowel_requires_tongue_body",
+                ));
+            }
+            if matches!(height, TongueHeight::Close | TongueHeight::NearClose) {
+                return Err(self.phoneme_anatomically_impossible_with_reason(
                     phoneme_ipa,
-                    "Nasale erfordern has_soft_palate = true",
+                    "close_vowel_requires_tongue_body",
                 ));
             }
         }
+        if rounded && matches!(self.lips, LipControl::None) {
+            return Err(self.phoneme_anatomically_impossible_with_reason(
+                phoneme_ipa,
+                "rounded_vowel_requires_lips",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Validates secondary articulations such as [kʷ]
+    pub fn validate_secondary_articulation(
+        &self,
+        phoneme_ipa: &str,
+        diacritics: &[Diacritics],
+    ) -> Result<(), ValidationError> {
+        if diacritics.contains(&Diacritics::Labialized) && matches!(self.lips, LipControl::None) {
+            return Err(self.phoneme_anatomically_impossible_with_reason(
+                phoneme_ipa,
+                "labialized_requires_lips",
+            ));
+        }
         Ok(())
     }
 }
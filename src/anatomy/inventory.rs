@@ -0,0 +1,170 @@
+//! Phoneme inventory queries for a speaker anatomy
+
+use crate::anatomy::speaker::{SpeakerAnatomy, TonguePartControl};
+use crate::phonology::consonants::all_consonant_phonemes;
+use crate::phonology::phonemes::Consonant;
+use crate::phonology::PhonologyConfiguration;
+use crate::syllables::patterns::SyllablePattern;
+use crate::validation::ValidationErrors;
+
+impl SpeakerAnatomy {
+    /// Whether this speaker can articulate `consonant`
+    pub fn can_produce(&self, consonant: &Consonant) -> bool {
+        consonant.validate_against_anatomy(self).is_ok()
+    }
+
+    /// Checks every consonant and vowel of `phonology` against this speaker.
+    /// Failures are collected under `consonant_<ipa>` / `vowel_<ipa>` keys.
+    pub fn validate_inventory(
+        &self,
+        phonology: &PhonologyConfiguration,
+    ) -> Result<(), ValidationErrors> {
+        phonology.validate_against_anatomy(self)
+    }
+
+    /// All known consonants this speaker can articulate
+    pub fn producible_phonemes(&self) -> Vec<&'static Consonant> {
+        all_consonant_phonemes()
+            .into_iter()
+            .filter(|c| self.can_produce(c))
+            .collect()
+    }
+
+    /// The producible consonant closest to `phoneme` by articulatory features,
+    /// e.g. [f] → [ɸ] for a speaker without teeth. Returns `phoneme`'s own
+    /// static if it is producible, and `None` if nothing is.
+    pub fn nearest_producible(&self, phoneme: &Consonant) -> Option<&'static Consonant> {
+        self.producible_phonemes()
+            .into_iter()
+            .min_by_key(|candidate| feature_distance(phoneme, candidate))
+    }
+
+    /// Proposes weighted syllable patterns the speaker can plausibly handle.
+    /// Clusters need fast transitions between articulators, so they are only
+    /// suggested when several tongue parts are agile; with at most one agile
+    /// part only CV remains.
+    pub fn suggest_syllable_structure(&self) -> Vec<SyllablePattern> {
+        let agile_parts = [&self.tongue.tip, &self.tongue.blade, &self.tongue.body]
+            .into_iter()
+            .filter(|part| matches!(part, TonguePartControl::Agile))
+            .count();
+
+        let schemas: &[(&str, f32)] = match agile_parts {
+            0 | 1 => &[("CV", 1.0)],
+            2 => &[("CV", 0.6), ("CVC", 0.3), ("V", 0.1)],
+            _ => &[
+                ("CV", 0.4),
+                ("CVC", 0.3),
+                ("CCV", 0.1),
+                ("CVCC", 0.1),
+                ("V", 0.1),
+            ],
+        };
+
+        schemas
+            .iter()
+            .map(|(schema, weight)| SyllablePattern::new(schema, *weight).parse())
+            .collect()
+    }
+}
+
+/// Weighted count of differing features; airflow and place weigh more than
+/// subplace and voicing, so substitutes stay close to the original sound
+fn feature_distance(a: &Consonant, b: &Consonant) -> usize {
+    let (a, b) = (&a.phoneme, &b.phoneme);
+    let mut distance = 0;
+
+    if a.airflow != b.airflow {
+        distance += 3;
+    }
+    if a.place != b.place {
+        distance += 2;
+    }
+    if a.subplace != b.subplace {
+        distance += 1;
+    }
+    if a.manner != b.manner {
+        distance += 2;
+    }
+    distance += a
+        .diacritics
+        .iter()
+        .filter(|d| !b.diacritics.contains(d))
+        .count();
+    distance += b
+        .diacritics
+        .iter()
+        .filter(|d| !a.diacritics.contains(d))
+        .count();
+
+    distance
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::anatomy::speaker::{LipControl, TeethConfiguration, TonguePartControl};
+    use crate::phonology::consonants::pulmonics::{F, K_VELAR, PH, TH};
+    use crate::phonology::{A, U};
+
+    #[test]
+    fn test_toothless_speaker_substitutes_bilabial_fricative() {
+        let toothless = SpeakerAnatomy::human().teeth(TeethConfiguration::None);
+
+        assert!(!toothless.can_produce(&F));
+        assert_eq!(toothless.nearest_producible(&F), Some(&PH));
+        assert_eq!(SpeakerAnatomy::human().nearest_producible(&F), Some(&F));
+    }
+
+    #[test]
+    fn test_inventory_reports_only_impossible_phonemes() {
+        let toothless = SpeakerAnatomy::human().teeth(TeethConfiguration::None);
+        let phonology = PhonologyConfiguration::new()
+            .add_consonants(vec![&F, &PH, &TH, &K_VELAR])
+            .unwrap()
+            .add_vowels(vec![&A, &U])
+            .unwrap();
+
+        let errors = toothless.validate_inventory(&phonology).unwrap_err();
+        let keys: Vec<&str> = errors.iter().map(|(key, _)| key).collect();
+        assert_eq!(keys, vec!["consonant_f", "consonant_θ"]);
+        assert!(SpeakerAnatomy::human()
+            .validate_inventory(&phonology)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_vowels_need_lips_and_tongue_body() {
+        let phonology = PhonologyConfiguration::new()
+            .add_vowels(vec![&A, &U])
+            .unwrap();
+
+        let lipless = SpeakerAnatomy::human().lips(LipControl::None);
+        let errors = lipless.validate_inventory(&phonology).unwrap_err();
+        assert!(errors.has_error("vowel_u"));
+        assert!(!errors.has_error("vowel_a"));
+
+        let no_body = SpeakerAnatomy::human().tongue_body(TonguePartControl::None);
+        assert!(no_body
+            .validate_inventory(&phonology)
+            .unwrap_err()
+            .has_error("vowel_u"));
+    }
+
+    #[test]
+    fn test_minimal_anatomy_suggests_only_cv() {
+        let minimal = SpeakerAnatomy::human()
+            .tongue_tip(TonguePartControl::Limited)
+            .tongue_blade(TonguePartControl::None);
+        let schemas = |anatomy: &SpeakerAnatomy| {
+            anatomy
+                .suggest_syllable_structure()
+                .into_iter()
+                .map(|p| p.schema)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(schemas(&minimal), vec!["CV"]);
+        assert!(schemas(&SpeakerAnatomy::human()).contains(&"CCV".to_string()));
+    }
+}
@@ -6,7 +6,7 @@ use crate::anatomy::speaker::SpeakerAnatomy;
 use crate::phonology::PhonologyConfiguration;
 
 use crate::syllables::{
-    self, NoCoda, NoNucleus, NoOnset, SyllableConfiguration, WithCoda, WithNucleus, WithOnset,
+    NoCoda, NoNucleus, NoOnset, Syllable, SyllableConfiguration, WithCoda, WithNucleus, WithOnset,
 };
 use crate::validation::{ValidationError, ValidationErrors};
 
@@ -48,7 +48,6 @@ impl LanguageConfiguration<NoOnset, NoNucleus, NoCoda, NotInitialized> {
 impl<OnsetState, NucleusState, CodaState>
     LanguageConfiguration<OnsetState, NucleusState, CodaState, Initializing>
 {
-    #[must_use]
     pub fn set_anatomy(
         mut self,
         anatomy: SpeakerAnatomy,
@@ -78,7 +77,6 @@ impl<OnsetState, NucleusState, CodaState>
         }
     }
 
-    #[must_use]
     pub fn set_phonology(
         mut self,
         phonology: PhonologyConfiguration,
@@ -115,7 +113,6 @@ impl<OnsetState, NucleusState, CodaState>
         }
     }
 
-    #[must_use]
     pub fn set_syllables(
         self,
         syllables: SyllableConfiguration<WithOnset, WithNucleus, WithCoda>,
@@ -130,7 +127,7 @@ impl<OnsetState, NucleusState, CodaState>
                 name: self.name,
                 speaker_anatomy: self.speaker_anatomy,
                 phonology: self.phonology,
-                syllables: syllables,
+                syllables,
                 _state: PhantomData,
             }),
             Err(e) => {
@@ -141,6 +138,15 @@ impl<OnsetState, NucleusState, CodaState>
     }
 }
 
+impl<OnsetState, NucleusState, CodaState, State>
+    LanguageConfiguration<OnsetState, NucleusState, CodaState, State>
+{
+    /// Syllabifies IPA tokens with this language's phonology and onset rules
+    pub fn syllabify(&self, tokens: &[String]) -> Result<Vec<Syllable>, ValidationErrors> {
+        self.syllables.syllabify(tokens, &self.phonology)
+    }
+}
+
 impl LanguageConfiguration<WithOnset, WithNucleus, WithCoda, Initialized> {
     pub fn name(&self) -> &str {
         &self.name
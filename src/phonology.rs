@@ -1,9 +1,12 @@
 mod builder;
 pub mod consonants;
 pub(crate) mod errors;
+pub mod ipa;
 pub(crate) mod macros;
 pub mod phonemes;
+pub mod stats;
 pub mod vowels;
+pub mod xsampa;
 
 pub use crate::phonology::vowels::*;
 
@@ -12,7 +15,7 @@ use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
 
 pub const EMPTY_DIACRITICS: Cow<'static, [Diacritics]> = Cow::Borrowed(&[]);
-pub const EMPTY_SUPRASEGEMENTALS: Cow<'static, [Suprasegmentals]> = Cow::Borrowed(&[]);
+pub const EMPTY_SUPRASEGEMENTALS: Cow<'static, [Suprasegmental]> = Cow::Borrowed(&[]);
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PhonologyConfiguration {
@@ -29,7 +32,7 @@ pub enum AirflowMechanism {
     Implosive,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Manner {
     Nasal,                // m, n, ŋ
     Plosive,              // p, b, t, d, k, g
@@ -42,7 +45,33 @@ pub enum Manner {
     Affricate,            // tʃ, dʒ, ts, dz
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+impl Manner {
+    /// Position on the sonority hierarchy, from stops (lowest) to glides
+    pub fn sonority(&self) -> u8 {
+        match self {
+            Manner::Plosive | Manner::Affricate => 1,
+            Manner::SibilantFricative | Manner::NonSibilantFricative => 2,
+            Manner::Nasal => 3,
+            Manner::LateralApproximant | Manner::TapFlap | Manner::Trill => 4,
+            Manner::Approximant => 5,
+        }
+    }
+}
+
+/// Orders consonants into an onset with rising sonority toward the nucleus.
+/// Returns `None` if two of them share a sonority level (e.g. two stops),
+/// since no ordering of those forms a legal rise.
+pub fn assemble_onset<'a>(phonemes: &[&'a Consonant]) -> Option<Vec<&'a Consonant>> {
+    let mut onset = phonemes.to_vec();
+    onset.sort_by_key(|c| c.phoneme.manner.sonority());
+
+    onset
+        .windows(2)
+        .all(|pair| pair[0].phoneme.manner.sonority() < pair[1].phoneme.manner.sonority())
+        .then_some(onset)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Place {
     Labial,    // p, b, f, v, m
     Coronal,   // t, d, s, z, n, l, r
@@ -129,6 +158,158 @@ pub enum Diacritics {
     Rhoticity,                 // [ɚ] (◌˞)
 }
 
+/// Other common spellings of a diacritic: the ring above for letters with a
+/// descender (ŋ̊) and the spacing tacks (ɹ̠˔)
+const ALTERNATE_SYMBOLS: [(char, Diacritics); 3] = [
+    ('\u{030A}', Diacritics::Voiceless),
+    ('\u{02D4}', Diacritics::Raised),
+    ('\u{02D5}', Diacritics::Lowered),
+];
+
+impl Diacritics {
+    /// Every diacritic, in declaration order
+    pub const ALL: &'static [Diacritics] = &[
+        Diacritics::Long,
+        Diacritics::Ejective,
+        Diacritics::Voiced,
+        Diacritics::Voiceless,
+        Diacritics::BreathyVoiced,
+        Diacritics::CreakyVoiced,
+        Diacritics::Syllabic,
+        Diacritics::NonSyllabic,
+        Diacritics::Aspirated,
+        Diacritics::NoAudibleRelease,
+        Diacritics::NasalRelease,
+        Diacritics::LateralRelease,
+        Diacritics::VoicelessDentalFricativeRelease,
+        Diacritics::VoicelessVelarFricativeRelease,
+        Diacritics::MidCentralVowelRelease,
+        Diacritics::Dental,
+        Diacritics::Linguolabial,
+        Diacritics::Dentolabial,
+        Diacritics::Apical,
+        Diacritics::Laminal,
+        Diacritics::Advanced,
+        Diacritics::Retracted,
+        Diacritics::Centralized,
+        Diacritics::MiddleCentralized,
+        Diacritics::Raised,
+        Diacritics::Lowered,
+        Diacritics::MoreRounded,
+        Diacritics::LessRounded,
+        Diacritics::Labialized,
+        Diacritics::Palatalized,
+        Diacritics::Velarized,
+        Diacritics::Pharyngealized,
+        Diacritics::VelarizedOrPharyngealized,
+        Diacritics::AdvancedTongueRoot,
+        Diacritics::RetractedTongueRoot,
+        Diacritics::Nasalized,
+        Diacritics::Rhoticity,
+    ];
+
+    /// IPA symbol of the diacritic; combining marks are returned without a base
+    pub fn ipa_symbol(&self) -> &'static str {
+        match self {
+            Diacritics::Long => "ː",
+            Diacritics::Ejective => "ʼ",
+            Diacritics::Voiced => "\u{032C}",
+            Diacritics::Voiceless => "\u{0325}",
+            Diacritics::BreathyVoiced => "\u{0324}",
+            Diacritics::CreakyVoiced => "\u{0330}",
+            Diacritics::Syllabic => "\u{0329}",
+            Diacritics::NonSyllabic => "\u{032F}",
+            Diacritics::Aspirated => "ʰ",
+            Diacritics::NoAudibleRelease => "\u{031A}",
+            Diacritics::NasalRelease => "ⁿ",
+            Diacritics::LateralRelease => "ˡ",
+            Diacritics::VoicelessDentalFricativeRelease => "ᶿ",
+            Diacritics::VoicelessVelarFricativeRelease => "ˣ",
+            Diacritics::MidCentralVowelRelease => "ᵊ",
+            Diacritics::Dental => "\u{032A}",
+            Diacritics::Linguolabial => "\u{033C}",
+            Diacritics::Dentolabial => "\u{0346}",
+            Diacritics::Apical => "\u{033A}",
+            Diacritics::Laminal => "\u{033B}",
+            Diacritics::Advanced => "\u{031F}",
+            Diacritics::Retracted => "\u{0320}",
+            Diacritics::Centralized => "\u{0308}",
+            Diacritics::MiddleCentralized => "\u{033D}",
+            Diacritics::Raised => "\u{031D}",
+            Diacritics::Lowered => "\u{031E}",
+            Diacritics::MoreRounded => "\u{0339}",
+            Diacritics::LessRounded => "\u{031C}",
+            Diacritics::Labialized => "ʷ",
+            Diacritics::Palatalized => "ʲ",
+            Diacritics::Velarized => "ˠ",
+            Diacritics::Pharyngealized => "ˤ",
+            Diacritics::VelarizedOrPharyngealized => "\u{0334}",
+            Diacritics::AdvancedTongueRoot => "\u{0318}",
+            Diacritics::RetractedTongueRoot => "\u{0319}",
+            Diacritics::Nasalized => "\u{0303}",
+            Diacritics::Rhoticity => "˞",
+        }
+    }
+
+    /// Sort key for stacking marks: combining marks first, then spacing
+    /// modifier letters, with length always last (`n̥ʷː`)
+    pub(crate) fn canonical_rank(&self) -> (u8, usize) {
+        let group = match self {
+            Diacritics::Long => 2,
+            d if d.is_combining() => 0,
+            _ => 1,
+        };
+        let position = Diacritics::ALL.iter().position(|d| d == self).unwrap_or(0);
+        (group, position)
+    }
+
+    fn is_combining(&self) -> bool {
+        self.ipa_symbol()
+            .chars()
+            .all(|c| ('\u{0300}'..='\u{036F}').contains(&c))
+    }
+
+    /// Splits trailing diacritics off an IPA string: "n̥ʷ" → ("n", [Voiceless, Labialized]).
+    /// Inverse of [`Consonant::with_diacritics`](phonemes::Consonant::with_diacritics);
+    /// also accepts the alternate spellings ŋ̊ (voiceless), ˔ (raised) and ˕ (lowered).
+    pub fn parse_ipa(ipa: &str) -> (&str, Vec<Diacritics>) {
+        let mut base = ipa;
+        let mut diacritics = Vec::new();
+
+        while let Some(last) = base.chars().last() {
+            let Some(diacritic) = Diacritics::ALL
+                .iter()
+                .copied()
+                .find(|d| d.ipa_symbol().chars().eq(std::iter::once(last)))
+                .or_else(|| {
+                    ALTERNATE_SYMBOLS
+                        .iter()
+                        .find(|(symbol, _)| *symbol == last)
+                        .map(|(_, d)| *d)
+                })
+            else {
+                break;
+            };
+            diacritics.push(diacritic);
+            base = &base[..base.len() - last.len_utf8()];
+        }
+
+        diacritics.reverse();
+        (base, diacritics)
+    }
+
+    /// Labialization, palatalization, velarization and pharyngealization
+    pub fn is_secondary_articulation(&self) -> bool {
+        matches!(
+            self,
+            Diacritics::Labialized
+                | Diacritics::Palatalized
+                | Diacritics::Velarized
+                | Diacritics::Pharyngealized
+        )
+    }
+}
+
 // Vowel enums
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum TonguePosition {
@@ -154,11 +335,50 @@ pub enum Roundness {
     Unrounded, // i, e, ɛ, a
 }
 
-// Suprasegmentals features
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
-pub enum Suprasegmentals {
-    Stress,          // Primary stress
-    SecondaryStress, // Secondary stress
-    Tone,            // Tonal features
-    Length,          // Phonemic length
+/// Pitch level of a tone, rendered as a Chao tone letter
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ToneLevel {
+    ExtraHigh, // ˥
+    High,      // ˦
+    Mid,       // ˧
+    Low,       // ˨
+    ExtraLow,  // ˩
+}
+
+impl ToneLevel {
+    pub fn ipa_symbol(&self) -> &'static str {
+        match self {
+            ToneLevel::ExtraHigh => "˥",
+            ToneLevel::High => "˦",
+            ToneLevel::Mid => "˧",
+            ToneLevel::Low => "˨",
+            ToneLevel::ExtraLow => "˩",
+        }
+    }
+}
+
+/// Suprasegmental marks a syllable nucleus or a phoneme can carry
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Suprasegmental {
+    Long,              // aː
+    HalfLong,          // aˑ
+    Tone(ToneLevel),   // a˥
+    Nasalized,         // ã
+    Stressed,          // ˈa
+    SecondaryStressed, // ˌa
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::phonology::consonants::pulmonics::{B_BILABIAL, K_VELAR, P_BILABIAL, TURNED_R};
+
+    #[test]
+    fn test_assemble_onset_rises_in_sonority() {
+        let onset = assemble_onset(&[&TURNED_R, &B_BILABIAL]).unwrap();
+        let ipa: String = onset.iter().map(|c| c.ipa.as_ref()).collect();
+        assert_eq!(ipa, "bɹ");
+
+        assert!(assemble_onset(&[&P_BILABIAL, &K_VELAR]).is_none());
+    }
 }
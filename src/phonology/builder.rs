@@ -89,9 +89,7 @@ impl PhonologyConfiguration {
         // Validiere alle Konsonanten
         for consonant_cow in &self.consonants {
             let consonant = consonant_cow.as_ref();
-            if let Err(validation_error) =
-                self.validate_consonant_against_anatomy(consonant, anatomy)
-            {
+            if let Err(validation_error) = consonant.validate_against_anatomy(anatomy) {
                 errors.add(format!("consonant_{}", consonant.ipa), validation_error);
             }
         }
@@ -110,79 +108,69 @@ impl PhonologyConfiguration {
             Err(errors)
         }
     }
-    // Hauptvalidierungsfunktion für einen Konsonanten
-    fn validate_consonant_against_anatomy(
+    fn validate_vowel_against_anatomy(
         &self,
-        consonant: &Consonant,
+        vowel: &Vowel,
         anatomy: &SpeakerAnatomy,
     ) -> Result<(), ValidationError> {
-        // Hier nutzt du die Funktionen aus errors.rs:
+        anatomy.validate_vowel_articulation(
+            &vowel.ipa,
+            vowel.phoneme.height,
+            vowel.phoneme.position,
+            matches!(vowel.phoneme.roundness, Roundness::Rounded),
+        )
+    }
+}
 
-        // Je nach consonant.place und consonant.manner die richtige Validierung aufrufen
-        match consonant.phoneme.place {
+impl Consonant {
+    /// Checks whether `anatomy` can articulate this consonant. The category
+    /// passed to each `SpeakerAnatomy::validate_*` helper is derived from the
+    /// consonant's own place, subplace, airflow and diacritics.
+    pub fn validate_against_anatomy(
+        &self,
+        anatomy: &SpeakerAnatomy,
+    ) -> Result<(), ValidationError> {
+        // Je nach place und manner die richtige Validierung aufrufen
+        match self.phoneme.place {
             Place::Labial => {
-                let subplace = self.determine_labial_subplace(consonant);
-                anatomy.validate_labial_articulation(&consonant.ipa, &subplace)?;
+                let subplace = self.determine_labial_subplace();
+                anatomy.validate_labial_articulation(&self.ipa, &subplace)?;
             }
             Place::Coronal => {
-                let subplace = self.determine_coronal_subplace(consonant);
-                anatomy.validate_coronal_articulation(&consonant.ipa, &subplace)?;
+                let subplace = self.determine_coronal_subplace();
+                anatomy.validate_coronal_articulation(&self.ipa, &subplace)?;
             }
             Place::Dorsal => {
-                let subplace = self.determine_dorsal_subplace(consonant);
-                anatomy.validate_dorsal_articulation(&consonant.ipa, &subplace)?;
+                let subplace = self.determine_dorsal_subplace();
+                anatomy.validate_dorsal_articulation(&self.ipa, &subplace)?;
             }
             Place::Laryngeal => {
                 // Laryngeal validation
             }
         }
 
+        // Sekundärartikulation (z.B. Labialisierung)
+        anatomy.validate_secondary_articulation(&self.ipa, &self.phoneme.diacritics)?;
+
         // Airflow-Validierung
-        let airflow_type = self.determine_airflow_type(consonant);
-        anatomy.validate_airflow_mechanism(&consonant.ipa, &airflow_type)?;
+        let airflow_type = self.determine_airflow_type();
+        anatomy.validate_airflow_mechanism(&self.ipa, &airflow_type)?;
 
         // Voicing-Validierung
-        let voicing_type = self.determine_voicing_type(consonant);
-        anatomy.validate_voicing_requirements(&consonant.ipa, &voicing_type)?;
+        let voicing_type = self.determine_voicing_type();
+        anatomy.validate_voicing_requirements(&self.ipa, &voicing_type)?;
 
         // Nasalität-Validierung
-        let is_nasal = matches!(consonant.phoneme.manner, Manner::Nasal);
-        anatomy.validate_nasality_requirements(&consonant.ipa, is_nasal)?;
+        let is_nasal = matches!(self.phoneme.manner, Manner::Nasal);
+        anatomy.validate_nasality_requirements(&self.ipa, is_nasal)?;
 
         Ok(())
     }
 
-    fn validate_vowel_against_anatomy(
-        &self,
-        vowel: &Vowel,
-        anatomy: &SpeakerAnatomy,
-    ) -> Result<(), ValidationError> {
-        // Vokale haben weniger anatomische Constraints als Konsonanten
-        // Aber du könntest hier z.B. prüfen:
-        // - Extreme Vokal-Positionen erfordern bestimmte Zungen-Agilität
-        // - Gerundete Vokale erfordern Lippen-Kontrolle
-
-        match vowel.phoneme.roundness {
-            Roundness::Rounded => {
-                // Gerundete Vokale [u, o, ɔ] erfordern Lippen-Kontrolle
-                if matches!(anatomy.lips, crate::anatomy::speaker::LipControl::None) {
-                    return Err(anatomy.phoneme_anatomically_impossible(
-                        &vowel.ipa,
-                        "Gerundete Vokale erfordern Lippen-Kontrolle",
-                    ));
-                }
-            }
-            Roundness::Unrounded => {
-                // Ungerundete Vokale haben keine speziellen Anforderungen
-            }
-        }
-
-        Ok(())
-    }
     // DETERMINE-FUNKTIONEN (basierend auf ConsonantConfiguration):
 
-    fn determine_labial_subplace(&self, consonant: &Consonant) -> String {
-        match consonant.phoneme.subplace {
+    fn determine_labial_subplace(&self) -> String {
+        match self.phoneme.subplace {
             Subplace::Bilabial => "bilabial".to_string(),
             Subplace::Labiodental => "labiodental".to_string(),
             Subplace::Linguolabial => "linguolabial".to_string(),
@@ -190,8 +178,8 @@ impl PhonologyConfiguration {
         }
     }
 
-    fn determine_coronal_subplace(&self, consonant: &Consonant) -> String {
-        match consonant.phoneme.subplace {
+    fn determine_coronal_subplace(&self) -> String {
+        match self.phoneme.subplace {
             Subplace::Dental => "dental".to_string(),
             Subplace::Alveolar => "alveolar".to_string(),
             Subplace::Postalveolar => "postalveolar".to_string(),
@@ -201,8 +189,8 @@ impl PhonologyConfiguration {
         }
     }
 
-    fn determine_dorsal_subplace(&self, consonant: &Consonant) -> String {
-        match consonant.phoneme.subplace {
+    fn determine_dorsal_subplace(&self) -> String {
+        match self.phoneme.subplace {
             Subplace::Palatal => "palatal".to_string(),
             Subplace::Velar => "velar".to_string(),
             Subplace::Uvular => "uvular".to_string(),
@@ -210,8 +198,8 @@ impl PhonologyConfiguration {
         }
     }
 
-    fn determine_airflow_type(&self, consonant: &Consonant) -> String {
-        match consonant.phoneme.airflow {
+    fn determine_airflow_type(&self) -> String {
+        match self.phoneme.airflow {
             AirflowMechanism::Pulmonic => "pulmonic".to_string(),
             AirflowMechanism::Click => "click".to_string(),
             AirflowMechanism::Ejective => "ejective".to_string(),
@@ -219,9 +207,9 @@ impl PhonologyConfiguration {
         }
     }
 
-    fn determine_voicing_type(&self, consonant: &Consonant) -> String {
+    fn determine_voicing_type(&self) -> String {
         // Prüfe Diacritics für Voicing-Information
-        for diacritic in consonant.phoneme.diacritics.iter() {
+        for diacritic in self.phoneme.diacritics.iter() {
             match diacritic {
                 Diacritics::Voiced => return "voiced".to_string(),
                 Diacritics::Voiceless => return "voiceless".to_string(),
@@ -232,7 +220,7 @@ impl PhonologyConfiguration {
         }
 
         // Fallback: Basierend auf typischer Voicing von Manner
-        match consonant.phoneme.manner {
+        match self.phoneme.manner {
             Manner::Nasal => "voiced".to_string(),
             Manner::Plosive => "voiceless".to_string(), // Default, kann überschrieben werden
             Manner::SibilantFricative => "voiceless".to_string(),
@@ -245,3 +233,78 @@ impl PhonologyConfiguration {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::anatomy::speaker::{LipControl, VelicPortControl};
+    use crate::phonology::consonants::pulmonics::{K_VELAR, N_VELAR};
+
+    #[test]
+    fn test_labialized_consonant_requires_lips() {
+        let labialized = K_VELAR
+            .with_secondary_articulation(Diacritics::Labialized)
+            .unwrap();
+        assert_eq!(labialized.ipa, "kʷ");
+
+        let mut phonology = PhonologyConfiguration::new();
+        phonology.consonants.push(Cow::Owned(labialized));
+
+        assert!(phonology
+            .validate_against_anatomy(&SpeakerAnatomy::human())
+            .is_ok());
+        let errors = phonology
+            .validate_against_anatomy(&SpeakerAnatomy::human().lips(LipControl::None))
+            .unwrap_err();
+        assert!(errors.has_error("consonant_kʷ"));
+    }
+
+    #[test]
+    fn test_only_secondary_articulations_are_accepted() {
+        let error = K_VELAR
+            .with_secondary_articulation(Diacritics::Aspirated)
+            .unwrap_err();
+        assert_eq!(error.code, "invalid_diacritic");
+    }
+
+    #[test]
+    fn test_second_secondary_articulation_is_rejected() {
+        let labialized = K_VELAR
+            .with_secondary_articulation(Diacritics::Labialized)
+            .unwrap();
+
+        for articulation in [Diacritics::Labialized, Diacritics::Palatalized] {
+            let error = labialized
+                .with_secondary_articulation(articulation)
+                .unwrap_err();
+            assert_eq!(error.code, "invalid_diacritic");
+            assert_eq!(error.params["reason"], "second_secondary_articulation");
+            assert_eq!(error.params["existing_diacritic"], "Labialized");
+        }
+    }
+
+    #[test]
+    fn test_velar_nasal_checks_dorsal_and_nasality() {
+        let reason = |anatomy: SpeakerAnatomy| {
+            N_VELAR
+                .validate_against_anatomy(&anatomy)
+                .unwrap_err()
+                .params["anatomical_reason"]
+                .as_str()
+                .unwrap()
+                .to_string()
+        };
+
+        assert!(N_VELAR
+            .validate_against_anatomy(&SpeakerAnatomy::human())
+            .is_ok());
+        assert!(
+            reason(SpeakerAnatomy::human().oral_cavity_has_soft_palate(false))
+                .starts_with("Velare")
+        );
+        assert!(
+            reason(SpeakerAnatomy::human().airflow_velic_port(VelicPortControl::None))
+                .starts_with("Nasale")
+        );
+    }
+}
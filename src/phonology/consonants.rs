@@ -6,8 +6,25 @@ pub mod pulmonics;
 //
 use super::consonants::{
     clicks::ALL_CLICKS, ejectives::ALL_EJECTIVES, implosives::ALL_IMPLOSIVES,
-    pulmonics::ALL_PULMONICS,
+    pulmonics::{ALL_PULMONICS, PULMONIC_PHONEMES},
 };
+use super::phonemes::Consonant;
 pub fn all_consonants() -> Vec<&'static str> {
     [ALL_PULMONICS, ALL_CLICKS, ALL_IMPLOSIVES, ALL_EJECTIVES].concat()
 }
+
+/// Every consonant static; clicks, ejectives and implosives are not defined yet
+pub fn all_consonant_phonemes() -> Vec<&'static Consonant> {
+    PULMONIC_PHONEMES.to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_phoneme_registry_matches_names() {
+        let names: Vec<&str> = PULMONIC_PHONEMES.iter().map(|c| c.name.as_ref()).collect();
+        assert_eq!(names, ALL_PULMONICS);
+    }
+}
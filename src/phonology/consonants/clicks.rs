@@ -1 +1 @@
-pub const ALL_CLICKS: &[&'static str] = &[];
+pub const ALL_CLICKS: &[&str] = &[];
@@ -1 +1 @@
-pub const ALL_EJECTIVES: &[&'static str] = &[];
+pub const ALL_EJECTIVES: &[&str] = &[];
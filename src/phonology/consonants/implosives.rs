@@ -1 +1 @@
-pub const ALL_IMPLOSIVES: &[&'static str] = &[];
+pub const ALL_IMPLOSIVES: &[&str] = &[];
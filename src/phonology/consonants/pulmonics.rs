@@ -1,6 +1,7 @@
 use crate::define_ipa_phoneme;
 use crate::phonology::{
-    AirflowMechanism, Diacritics, Manner, Place, Subplace, EMPTY_SUPRASEGEMENTALS,
+    phonemes::Consonant, AirflowMechanism, Diacritics, Manner, Place, Subplace,
+    EMPTY_SUPRASEGEMENTALS,
 };
 use std::borrow::Cow;
 
@@ -74,7 +75,7 @@ define_ipa_phoneme! {
         manner: Manner::Nasal,
         place: Place::Labial,
         subplace: Subplace::Linguolabial,
-        diacritics: Cow::Borrowed(&[Diacritics::Voiced]),
+        diacritics: Cow::Borrowed(&[Diacritics::Voiced, Diacritics::Linguolabial]),
         suprasegmentals: EMPTY_SUPRASEGEMENTALS,
     }
 }
@@ -250,7 +251,7 @@ define_ipa_phoneme! {
         manner: Manner::Plosive,
         place: Place::Labial,
         subplace: Subplace::Labiodental,
-        diacritics: Cow::Borrowed(&[Diacritics::Voiceless]),
+        diacritics: Cow::Borrowed(&[Diacritics::Voiceless, Diacritics::Dental]),
         suprasegmentals: EMPTY_SUPRASEGEMENTALS,
     }
 }
@@ -262,7 +263,7 @@ define_ipa_phoneme! {
         manner: Manner::Plosive,
         place: Place::Labial,
         subplace: Subplace::Labiodental,
-        diacritics: Cow::Borrowed(&[Diacritics::Voiced]),
+        diacritics: Cow::Borrowed(&[Diacritics::Voiced, Diacritics::Dental]),
         suprasegmentals: EMPTY_SUPRASEGEMENTALS,
     }
 }
@@ -274,7 +275,7 @@ define_ipa_phoneme! {
         manner: Manner::Plosive,
         place: Place::Labial,
         subplace: Subplace::Linguolabial,
-        diacritics: Cow::Borrowed(&[Diacritics::Voiceless]),
+        diacritics: Cow::Borrowed(&[Diacritics::Voiceless, Diacritics::Linguolabial]),
         suprasegmentals: EMPTY_SUPRASEGEMENTALS,
     }
 }
@@ -286,7 +287,7 @@ define_ipa_phoneme! {
         manner: Manner::Plosive,
         place: Place::Labial,
         subplace: Subplace::Linguolabial,
-        diacritics: Cow::Borrowed(&[Diacritics::Voiced]),
+        diacritics: Cow::Borrowed(&[Diacritics::Voiced, Diacritics::Linguolabial]),
         suprasegmentals: EMPTY_SUPRASEGEMENTALS,
     }
 }
@@ -629,7 +630,7 @@ define_ipa_phoneme! {
         manner: Manner::NonSibilantFricative,
         place: Place::Labial,
         subplace: Subplace::Linguolabial,
-        diacritics: Cow::Borrowed(&[Diacritics::Voiceless]),
+        diacritics: Cow::Borrowed(&[Diacritics::Voiceless, Diacritics::Linguolabial]),
         suprasegmentals: EMPTY_SUPRASEGEMENTALS,
     }
 }
@@ -641,7 +642,7 @@ define_ipa_phoneme! {
         manner: Manner::NonSibilantFricative,
         place: Place::Labial,
         subplace: Subplace::Linguolabial,
-        diacritics: Cow::Borrowed(&[Diacritics::Voiced]),
+        diacritics: Cow::Borrowed(&[Diacritics::Voiced, Diacritics::Linguolabial]),
         suprasegmentals: EMPTY_SUPRASEGEMENTALS,
     }
 }
@@ -677,7 +678,7 @@ define_ipa_phoneme! {
         manner: Manner::NonSibilantFricative,
         place: Place::Coronal,
         subplace: Subplace::Alveolar,
-        diacritics: Cow::Borrowed(&[Diacritics::Voiceless]),
+        diacritics: Cow::Borrowed(&[Diacritics::Voiceless, Diacritics::Retracted]),
         suprasegmentals: EMPTY_SUPRASEGEMENTALS,
     }
 }
@@ -689,7 +690,7 @@ define_ipa_phoneme! {
         manner: Manner::NonSibilantFricative,
         place: Place::Coronal,
         subplace: Subplace::Alveolar,
-        diacritics: Cow::Borrowed(&[Diacritics::Voiced]),
+        diacritics: Cow::Borrowed(&[Diacritics::Voiced, Diacritics::Retracted]),
         suprasegmentals: EMPTY_SUPRASEGEMENTALS,
     }
 }
@@ -922,6 +923,8 @@ define_ipa_phoneme! {
         suprasegmentals: EMPTY_SUPRASEGEMENTALS,
     }
 }
+// Written with the lowering mark U+031E and declares `Diacritics::Lowered`;
+// the `_RAISED` name does not match the mark and is kept for compatibility
 define_ipa_phoneme! {
     name: GLOTTAL_RAISED,
     ipa: "ʔ̞",
@@ -930,7 +933,7 @@ define_ipa_phoneme! {
         manner: Manner::Approximant,
         place: Place::Laryngeal,
         subplace: Subplace::Glottal,
-        diacritics: Cow::Borrowed(&[Diacritics::Voiced, Diacritics::Raised]),
+        diacritics: Cow::Borrowed(&[Diacritics::Voiced, Diacritics::Lowered]),
         suprasegmentals: EMPTY_SUPRASEGEMENTALS,
     }
 }
@@ -967,7 +970,7 @@ define_ipa_phoneme! {
         manner: Manner::TapFlap,
         place: Place::Labial,
         subplace: Subplace::Linguolabial,
-        diacritics: Cow::Borrowed(&[Diacritics::Voiced]),
+        diacritics: Cow::Borrowed(&[Diacritics::Voiced, Diacritics::Linguolabial]),
         suprasegmentals: EMPTY_SUPRASEGEMENTALS,
     }
 }
@@ -997,7 +1000,7 @@ define_ipa_phoneme! {
 }
 
 // Continue at TAP/RETROFLEX => https://en.wikipedia.org/wiki/International_Phonetic_Alphabet
-pub const ALL_PULMONICS: &[&'static str] = &[
+pub const ALL_PULMONICS: &[&str] = &[
     stringify!(M_BILABIAL),
     stringify!(M_BILABIAL_VOICELESS),
     stringify!(M_LABIODENTAL),
@@ -1051,80 +1054,7 @@ pub const ALL_PULMONICS: &[&'static str] = &[
     stringify!(DH_ALVEOLAR),
     stringify!(TURNED_R_VOIVELESS_RETRACTED_RAISED),
     stringify!(TURNED_R_RETRACTED_RAISED),
-    stringify!(TURNED_R_RETROFLEX_RAISED),
-    stringify!(F_PALATAL),
-    stringify!(V_PALATAL),
-    stringify!(X),
-    stringify!(RAMS_HORN),
-    stringify!(X_UVULAR),
-    stringify!(REVERSED_TRUNED_R),
-    stringify!(H_BAR),
-    stringify!(GH_PHARYNGEAL),
-    stringify!(H),
-    stringify!(H_VOICED),
-    stringify!(ROUNDED_V),
-    stringify!(TURNED_R),
-    stringify!(TURNED_R_RETROFLEX),
-    stringify!(J),
-    stringify!(TURNED_M_VELAR),
-    stringify!(GLOTTAL_RAISED),
-    stringify!(V_RIGHT_HOOK_ADVANCED),
-    stringify!(V_RIGHT_HOOK),
-    stringify!(REVERSED_ROUNDED_R_LINGUOLABIAL),
-    stringify!(REVERSED_ROUNDED_R_),
-    stringify!(REVERSED_ROUNDED_R_VOICELESS    name: M_BILABIAL),
-    stringify!(M_BILABIAL_VOICELESS),
-    stringify!(M_LABIODENTAL),
-    stringify!(M_LABIODENTAL_VOICELESS),
-    stringify!(N_LINGUOLABIAL),
-    stringify!(N_ALVEOLAR),
-    stringify!(N_ALVEOLAR_VOICELESS),
-    stringify!(N_RETROFLEX),
-    stringify!(N_RETROFLEX_VOICELESS),
-    stringify!(N_PALATAL),
-    stringify!(N_PALATAL_VOICELESS),
-    stringify!(N_VELAR),
-    stringify!(N_VELAR_VOICELESS),
-    stringify!(N_UVULAR),
-    stringify!(N_UVULAR_VOICELESS),
-    stringify!(P_BILABIAL),
-    stringify!(B_BILABIAL),
-    stringify!(P_LABIODENTAL),
-    stringify!(B_LABIODENTAL),
-    stringify!(T_LINGUOLABIAL),
-    stringify!(D_LINGUOLABIAL),
-    stringify!(T_ALVEOLAR),
-    stringify!(D_ALVEOLAR),
-    stringify!(T_RETROFLEX),
-    stringify!(D_RETROFLEX),
-    stringify!(C_PALATAL_PLOSIVE),
-    stringify!(J_PALATAL_PLOSIVE),
-    stringify!(K_VELAR),
-    stringify!(G_VELAR),
-    stringify!(Q_UVULAR),
-    stringify!(G_UVULAR),
-    stringify!(STOP_PHARYNGEAL),
-    stringify!(STOP_GLOTTAL),
-    stringify!(S_ALVEOLAR),
-    stringify!(Z_ALVEOLAR),
-    stringify!(S_POSTALVEOLAR),
-    stringify!(Z_POSTALVEOLAR),
-    stringify!(S_RETROFLEX),
-    stringify!(Z_RETROFLEX),
-    stringify!(S_PALATAL),
-    stringify!(Z_PALATAL),
-    stringify!(PH),
-    stringify!(BH),
-    stringify!(F),
-    stringify!(V),
-    stringify!(TH_LINGUOLABIAL),
-    stringify!(DH_LINGUOLABIAL),
-    stringify!(TH),
-    stringify!(DH),
-    stringify!(TH_ALVEOLAR),
-    stringify!(DH_ALVEOLAR),
-    stringify!(TURNED_R_VOIVELESS_RETRACTED_RAISED),
-    stringify!(TURNED_R_RETRACTED_RAISED),
+    stringify!(TURNED_R_VOIVELESS_RETROFLEX_RAISED),
     stringify!(TURNED_R_RETROFLEX_RAISED),
     stringify!(F_PALATAL),
     stringify!(V_PALATAL),
@@ -1148,3 +1078,83 @@ pub const ALL_PULMONICS: &[&'static str] = &[
     stringify!(REVERSED_ROUNDED_R_),
     stringify!(REVERSED_ROUNDED_R_VOICELESS),
 ];
+
+/// All pulmonic consonant statics, for lookups by IPA symbol
+pub static PULMONIC_PHONEMES: &[&Consonant] = &[
+    &M_BILABIAL,
+    &M_BILABIAL_VOICELESS,
+    &M_LABIODENTAL,
+    &M_LABIODENTAL_VOICELESS,
+    &N_LINGUOLABIAL,
+    &N_ALVEOLAR,
+    &N_ALVEOLAR_VOICELESS,
+    &N_RETROFLEX,
+    &N_RETROFLEX_VOICELESS,
+    &N_PALATAL,
+    &N_PALATAL_VOICELESS,
+    &N_VELAR,
+    &N_VELAR_VOICELESS,
+    &N_UVULAR,
+    &N_UVULAR_VOICELESS,
+    &P_BILABIAL,
+    &B_BILABIAL,
+    &P_LABIODENTAL,
+    &B_LABIODENTAL,
+    &T_LINGUOLABIAL,
+    &D_LINGUOLABIAL,
+    &T_ALVEOLAR,
+    &D_ALVEOLAR,
+    &T_RETROFLEX,
+    &D_RETROFLEX,
+    &C_PALATAL_PLOSIVE,
+    &J_PALATAL_PLOSIVE,
+    &K_VELAR,
+    &G_VELAR,
+    &Q_UVULAR,
+    &G_UVULAR,
+    &STOP_PHARYNGEAL,
+    &STOP_GLOTTAL,
+    &S_ALVEOLAR,
+    &Z_ALVEOLAR,
+    &S_POSTALVEOLAR,
+    &Z_POSTALVEOLAR,
+    &S_RETROFLEX,
+    &Z_RETROFLEX,
+    &S_PALATAL,
+    &Z_PALATAL,
+    &PH,
+    &BH,
+    &F,
+    &V,
+    &TH_LINGUOLABIAL,
+    &DH_LINGUOLABIAL,
+    &TH,
+    &DH,
+    &TH_ALVEOLAR,
+    &DH_ALVEOLAR,
+    &TURNED_R_VOIVELESS_RETRACTED_RAISED,
+    &TURNED_R_RETRACTED_RAISED,
+    &TURNED_R_VOIVELESS_RETROFLEX_RAISED,
+    &TURNED_R_RETROFLEX_RAISED,
+    &F_PALATAL,
+    &V_PALATAL,
+    &X,
+    &RAMS_HORN,
+    &X_UVULAR,
+    &REVERSED_TRUNED_R,
+    &H_BAR,
+    &GH_PHARYNGEAL,
+    &H,
+    &H_VOICED,
+    &ROUNDED_V,
+    &TURNED_R,
+    &TURNED_R_RETROFLEX,
+    &J,
+    &TURNED_M_VELAR,
+    &GLOTTAL_RAISED,
+    &V_RIGHT_HOOK_ADVANCED,
+    &V_RIGHT_HOOK,
+    &REVERSED_ROUNDED_R_LINGUOLABIAL,
+    &REVERSED_ROUNDED_R_,
+    &REVERSED_ROUNDED_R_VOICELESS,
+];
@@ -11,8 +11,9 @@ impl PhonologyConfiguration {
             .add_param("suggestion_count", suggestions.len().to_string())
             .add_param("similarity_algorithm", "levenshtein_distance")
             .with_message(format!(
-                "Unbekannter Konsonant '{}'. Meinten Sie: {:?}",
-                name, suggestions
+                "Unbekannter Konsonant '{}'. Meinten Sie: {}",
+                name,
+                suggestions.join(", ")
             ))
     }
     fn unknown_consonant_from_phonology(
@@ -21,13 +22,14 @@ impl PhonologyConfiguration {
         suggestions: Vec<String>,
     ) -> ValidationError {
         ValidationError::new("unknown_consonant_from_phonology")
-            .add_param("attemted_name", name)
+            .add_param("attempted_name", name)
             .add_param("suggestions", suggestions.join(", "))
             .add_param("suggestion_count", suggestions.len().to_string())
             .add_param("similarity_algorithm", "levenshtein_distance")
             .with_message(format!(
-                "Der Konsonant '{}' ist nicht in der Phonology der Sprache aufgeführt. Meinten Sie: {:?}",
-                name, suggestions
+                "Der Konsonant '{}' ist nicht in der Phonology der Sprache aufgeführt. Meinten Sie: {}",
+                name,
+                suggestions.join(", ")
             ))
     }
 
@@ -39,8 +41,9 @@ impl PhonologyConfiguration {
             .add_param("suggestion_count", suggestions.len().to_string())
             .add_param("similarity_algorithm", "levenshtein_distance")
             .with_message(format!(
-                "Unbekannter Vokal '{}'. Meinten Sie: {:?}",
-                name, suggestions
+                "Unbekannter Vokal '{}'. Meinten Sie: {}",
+                name,
+                suggestions.join(", ")
             ))
     }
 
@@ -57,8 +60,10 @@ impl PhonologyConfiguration {
             .add_param("available_diacritics", available.join(", "))
             .add_param("available_count", available.len().to_string())
             .with_message(format!(
-                "Ungültiges Diakritikum '{}' für '{}'. Verfügbar: {:?}",
-                diacritic, base, available
+                "Ungültiges Diakritikum '{}' für '{}'. Verfügbar: {}",
+                diacritic,
+                base,
+                available.join(", ")
             ))
     }
 
@@ -240,8 +245,9 @@ impl PhonologyConfiguration {
                 .add_param("suggestion_count", all_suggestions.len().to_string())
                 .add_param("similarity_algorithm", "levenshtein_distances")
                 .with_message(format!(
-                    "Unbekanntes Phonem '{}'. Meinten Sie: {:?}",
-                    attempted, all_suggestions
+                    "Unbekanntes Phonem '{}'. Meinten Sie: {}",
+                    attempted,
+                    all_suggestions.join(", ")
                 ))
         }
     }
@@ -262,11 +268,11 @@ pub fn levenshtein_distance(s1: &str, s2: &str) -> usize {
     let mut matrix = vec![vec![0; len2 + 1]; len1 + 1];
 
     // Initialize first row and column
-    for i in 0..=len1 {
-        matrix[i][0] = i;
+    for (i, row) in matrix.iter_mut().enumerate() {
+        row[0] = i;
     }
-    for j in 0..=len2 {
-        matrix[0][j] = j;
+    for (j, cell) in matrix[0].iter_mut().enumerate() {
+        *cell = j;
     }
 
     let chars1: Vec<char> = s1.chars().collect();
@@ -0,0 +1,113 @@
+//! IPA string handling
+//!
+//! Splits IPA strings back into phoneme units, the inverse of concatenating
+//! `Phoneme::ipa` values.
+
+use crate::phonology::PhonologyConfiguration;
+
+const TIE_BARS: [char; 2] = ['\u{0361}', '\u{035C}'];
+
+/// Spacing diacritics that belong to the preceding phoneme (ʰ, ʷ, ː, ...)
+const SPACING_DIACRITICS: &str = "ʰʷʲˠˤːˑʼⁿˡᶿˣᵊ˞˔˕";
+
+/// Returns true for marks that attach to the preceding phoneme
+fn is_attached_mark(c: char) -> bool {
+    (('\u{0300}'..='\u{036F}').contains(&c) && !TIE_BARS.contains(&c))
+        || SPACING_DIACRITICS.contains(c)
+}
+
+/// Splits `s` into phoneme tokens using longest match against the phonology.
+///
+/// Combining diacritics and spacing modifiers stay with the preceding
+/// phoneme, and tie bars join two phonemes into one token (t͡ʃ). Characters
+/// not in the phonology become single-character tokens; whitespace is skipped.
+pub fn tokenize(s: &str, phonology: &PhonologyConfiguration) -> Vec<String> {
+    let known: Vec<&str> = phonology
+        .consonants
+        .iter()
+        .map(|c| c.ipa.as_ref())
+        .chain(phonology.vowels.iter().map(|v| v.ipa.as_ref()))
+        .collect();
+
+    let mut tokens = Vec::new();
+    let mut rest = s;
+
+    while let Some(c) = rest.chars().next() {
+        if c.is_whitespace() {
+            rest = &rest[c.len_utf8()..];
+            continue;
+        }
+
+        let mut token = String::new();
+        loop {
+            let unit = next_unit(rest, &known);
+            token.push_str(unit);
+            rest = &rest[unit.len()..];
+
+            match rest.chars().next() {
+                Some(tie) if TIE_BARS.contains(&tie) => {
+                    token.push(tie);
+                    rest = &rest[tie.len_utf8()..];
+                    if rest.is_empty() {
+                        break;
+                    }
+                }
+                _ => break,
+            }
+        }
+        tokens.push(token);
+    }
+
+    tokens
+}
+
+/// Longest known phoneme at the start of `s` (or its first char), plus any
+/// attached marks
+fn next_unit<'a>(s: &'a str, known: &[&str]) -> &'a str {
+    let mut end = known
+        .iter()
+        .filter(|ipa| !ipa.is_empty() && s.starts_with(*ipa))
+        .map(|ipa| ipa.len())
+        .max()
+        .unwrap_or_else(|| s.chars().next().map_or(0, char::len_utf8));
+
+    for c in s[end..].chars() {
+        if !is_attached_mark(c) {
+            break;
+        }
+        end += c.len_utf8();
+    }
+
+    &s[..end]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::phonology::consonants::pulmonics::{N_VELAR, S_POSTALVEOLAR, T_ALVEOLAR};
+    use crate::phonology::O;
+
+    fn phonology() -> PhonologyConfiguration {
+        PhonologyConfiguration::new()
+            .add_consonants(vec![&T_ALVEOLAR, &S_POSTALVEOLAR, &N_VELAR])
+            .unwrap()
+            .add_vowels(vec![&O])
+            .unwrap()
+    }
+
+    #[test]
+    fn test_tie_bar_keeps_affricate_together() {
+        assert_eq!(tokenize("t͡ʃoŋ", &phonology()), vec!["t͡ʃ", "o", "ŋ"]);
+    }
+
+    #[test]
+    fn test_combining_diacritic_stays_with_phoneme() {
+        assert_eq!(tokenize("ŋ̊o", &phonology()), vec!["ŋ̊", "o"]);
+        assert_eq!(tokenize("tʰoː", &phonology()), vec!["tʰ", "oː"]);
+    }
+
+    #[test]
+    fn test_longest_match_and_unknown_characters() {
+        assert_eq!(tokenize("ʃox t", &phonology()), vec!["ʃ", "o", "x", "t"]);
+    }
+}
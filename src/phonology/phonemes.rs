@@ -1,7 +1,9 @@
 use crate::phonology::{
-    AirflowMechanism, Diacritics, Manner, Place, Roundness, Subplace, Suprasegmentals,
-    TongueHeight, TonguePosition,
+    AirflowMechanism, Diacritics, Manner, PhonologyConfiguration, Place, Roundness, Subplace,
+    Suprasegmental, TongueHeight, TonguePosition,
 };
+use crate::syllables::patterns::weight_in_range;
+use crate::validation::{ValidationError, ValidationErrors};
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
 
@@ -62,7 +64,78 @@ pub struct ConsonantConfiguration {
     pub place: Place,              // Labial, Alveolar, etc.
     pub subplace: Subplace,        // bilabial, labiodental, etc.
     pub diacritics: Cow<'static, [Diacritics]>, // Voiced, aspirated, etc.
-    pub suprasegmentals: Cow<'static, [Suprasegmentals]>, // Stress, tone, etc.
+    pub suprasegmentals: Cow<'static, [Suprasegmental]>, // Stress, tone, etc.
+}
+
+impl Consonant {
+    /// Builds a consonant with a secondary articulation, e.g. [k] + Labialized → [kʷ].
+    /// A consonant carries at most one secondary articulation, so [kʷ] + Labialized
+    /// or [tʲ] + Velarized are rejected.
+    pub fn with_secondary_articulation(
+        &self,
+        articulation: Diacritics,
+    ) -> Result<Consonant, ValidationError> {
+        if !articulation.is_secondary_articulation() {
+            return Err(ValidationError::new("invalid_diacritic")
+                .add_param("reason", "not_secondary_articulation")
+                .add_param("base_phoneme", self.ipa.to_string())
+                .add_param("attempted_diacritic", format!("{:?}", articulation))
+                .add_param(
+                    "available_diacritics",
+                    "Labialized, Palatalized, Velarized, Pharyngealized",
+                )
+                .with_message(format!(
+                    "{:?} ist keine Sekundärartikulation für '{}'",
+                    articulation, self.ipa
+                )));
+        }
+
+        if let Some(existing) = self
+            .phoneme
+            .diacritics
+            .iter()
+            .find(|d| d.is_secondary_articulation())
+        {
+            return Err(ValidationError::new("invalid_diacritic")
+                .add_param("reason", "second_secondary_articulation")
+                .add_param("base_phoneme", self.ipa.to_string())
+                .add_param("attempted_diacritic", format!("{:?}", articulation))
+                .add_param("existing_diacritic", format!("{:?}", existing))
+                .with_message(format!(
+                    "'{}' hat bereits die Sekundärartikulation {:?}, {:?} ist nicht möglich",
+                    self.ipa, existing, articulation
+                )));
+        }
+
+        let mut phoneme = self.phoneme.clone();
+        phoneme.diacritics.to_mut().push(articulation);
+
+        Ok(Phoneme {
+            ipa: Cow::Owned(format!("{}{}", self.ipa, articulation.ipa_symbol())),
+            name: Cow::Owned(format!("{}_{:?}", self.name, articulation).to_uppercase()),
+            phoneme,
+        })
+    }
+
+    /// Same manner, place and subplace, ignoring diacritics: [n] and [n̥] share a base
+    pub fn same_base(&self, other: &Consonant) -> bool {
+        let (a, b) = (&self.phoneme, &other.phoneme);
+        a.manner == b.manner && a.place == b.place && a.subplace == b.subplace
+    }
+
+    /// IPA string of this consonant with the given diacritics stacked in
+    /// canonical order, e.g. [n] + Voiceless → "n̥". Duplicates are dropped.
+    pub fn with_diacritics(&self, diacritics: &[Diacritics]) -> String {
+        let mut marks = diacritics.to_vec();
+        marks.sort_by_key(|d| d.canonical_rank());
+        marks.dedup();
+
+        let mut ipa = self.ipa.to_string();
+        for mark in marks {
+            ipa.push_str(mark.ipa_symbol());
+        }
+        ipa
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -100,6 +173,82 @@ pub struct AllowedCluster {
     pub weight: f32,
 }
 
+impl AllowedCluster {
+    /// Builds a cluster from IPA symbols, checked against a phonology
+    pub fn builder() -> AllowedClusterBuilder {
+        AllowedClusterBuilder::default()
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct AllowedClusterBuilder {
+    ipa: Vec<String>,
+    weight: Option<f32>,
+}
+
+impl AllowedClusterBuilder {
+    pub fn phoneme(mut self, ipa: impl Into<String>) -> Self {
+        self.ipa.push(ipa.into());
+        self
+    }
+
+    /// Weight in 0.0..=1.0 (default 1.0)
+    pub fn weight(mut self, weight: f32) -> Self {
+        self.weight = Some(weight);
+        self
+    }
+
+    /// Resolves every IPA symbol to its phoneme name in `phonology` and checks the weight
+    pub fn build(
+        self,
+        phonology: &PhonologyConfiguration,
+    ) -> Result<AllowedCluster, ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+        let weight = self.weight.unwrap_or(1.0);
+
+        if !weight_in_range(weight) {
+            errors.add(
+                "weight",
+                ValidationError::new("invalid_weight")
+                    .add_param("weight", weight)
+                    .with_message(format!("Gewicht {} liegt nicht in 0.0..=1.0", weight)),
+            );
+        }
+
+        let mut phonemes = Vec::with_capacity(self.ipa.len());
+        for (index, ipa) in self.ipa.iter().enumerate() {
+            let name = phonology
+                .consonants
+                .iter()
+                .map(|c| (c.ipa.as_ref(), c.name.as_ref()))
+                .chain(
+                    phonology
+                        .vowels
+                        .iter()
+                        .map(|v| (v.ipa.as_ref(), v.name.as_ref())),
+                )
+                .find(|(candidate, _)| candidate == ipa)
+                .map(|(_, name)| name);
+
+            match name {
+                Some(name) => phonemes.push(name.to_string()),
+                None => errors.add(
+                    format!("phoneme_{}", index),
+                    ValidationError::new("phoneme_not_found")
+                        .add_param("phoneme", ipa.clone())
+                        .with_message(format!("Phonem nicht gefunden: {}", ipa)),
+                ),
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(AllowedCluster { phonemes, weight })
+        } else {
+            Err(errors)
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AllowedDiphthong {
     pub first: String,
@@ -116,6 +265,107 @@ pub struct AllowedTriphthong {
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
 pub struct PhonemeCluster {
     pub phonemes: Vec<String>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::phonology::consonants::pulmonics::{
+        M_BILABIAL, N_ALVEOLAR, N_ALVEOLAR_VOICELESS, S_ALVEOLAR, T_ALVEOLAR,
+    };
+
+    fn phonology() -> PhonologyConfiguration {
+        PhonologyConfiguration::new()
+            .add_consonants(vec![&S_ALVEOLAR, &T_ALVEOLAR])
+            .unwrap()
+    }
+
+    #[test]
+    fn test_cluster_builder_resolves_names() {
+        let cluster = AllowedCluster::builder()
+            .phoneme("s")
+            .phoneme("t")
+            .weight(0.5)
+            .build(&phonology())
+            .unwrap();
+        assert_eq!(cluster.phonemes, vec!["S_ALVEOLAR", "T_ALVEOLAR"]);
+        assert_eq!(cluster.weight, 0.5);
+    }
+
+    #[test]
+    fn test_cluster_builder_rejects_bad_weight_and_unknown_phoneme() {
+        let errors = AllowedCluster::builder()
+            .phoneme("s")
+            .phoneme("q")
+            .weight(1.5)
+            .build(&phonology())
+            .unwrap_err();
+        assert!(errors.has_error("weight"));
+        assert!(errors.has_error("phoneme_1"));
+        assert!(!errors.has_error("phoneme_0"));
+    }
+
+    #[test]
+    fn test_with_diacritics_orders_marks() {
+        assert_eq!(
+            N_ALVEOLAR.with_diacritics(&[Diacritics::Voiceless]),
+            "n\u{0325}"
+        );
+
+        let ipa = N_ALVEOLAR.with_diacritics(&[
+            Diacritics::Long,
+            Diacritics::Labialized,
+            Diacritics::Voiceless,
+        ]);
+        assert_eq!(ipa, "n\u{0325}ʷː");
+        assert_eq!(
+            Diacritics::parse_ipa(&ipa),
+            (
+                "n",
+                vec![
+                    Diacritics::Voiceless,
+                    Diacritics::Labialized,
+                    Diacritics::Long
+                ]
+            )
+        );
+    }
+
+    #[test]
+    fn test_same_base_ignores_diacritics() {
+        assert!(N_ALVEOLAR.same_base(&N_ALVEOLAR_VOICELESS));
+        assert!(!N_ALVEOLAR.same_base(&M_BILABIAL));
+    }
+
+    #[test]
+    fn test_parse_ipa_matches_every_static() {
+        use crate::phonology::consonants::all_consonant_phonemes;
+        use crate::phonology::vowels::all_vowel_phonemes;
+
+        let statics = all_consonant_phonemes()
+            .into_iter()
+            .map(|c| (c.ipa.as_ref(), c.phoneme.diacritics.as_ref()))
+            .chain(
+                all_vowel_phonemes()
+                    .into_iter()
+                    .map(|v| (v.ipa.as_ref(), v.phoneme.diacritics.as_ref())),
+            );
+
+        for (ipa, declared) in statics {
+            let (base, parsed) = Diacritics::parse_ipa(ipa);
+            assert_eq!(base.chars().count(), 1, "unparsed marks in {}", ipa);
+            for diacritic in parsed {
+                assert!(
+                    declared.contains(&diacritic),
+                    "{} is written with {:?} but declares {:?}",
+                    ipa,
+                    diacritic,
+                    declared
+                );
+            }
+        }
+    }
+}
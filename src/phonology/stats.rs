@@ -0,0 +1,120 @@
+//! Inventory statistics for sanity-checking a phonology
+
+use crate::phonology::{Diacritics, Manner, PhonologyConfiguration, Place};
+use indexmap::IndexMap;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct InventoryStats {
+    pub consonants: usize,
+    pub vowels: usize,
+    /// Consonants per manner, in order of first appearance
+    pub by_manner: IndexMap<Manner, usize>,
+    /// Consonants per place, in order of first appearance
+    pub by_place: IndexMap<Place, usize>,
+    pub voiced: usize,
+    pub voiceless: usize,
+}
+
+impl InventoryStats {
+    pub fn total(&self) -> usize {
+        self.consonants + self.vowels
+    }
+
+    /// Share of voiced consonants (0.0 for an empty consonant inventory)
+    pub fn voiced_ratio(&self) -> f32 {
+        if self.consonants == 0 {
+            0.0
+        } else {
+            self.voiced as f32 / self.consonants as f32
+        }
+    }
+
+    /// Vowels per consonant (0.0 without consonants)
+    pub fn vowel_consonant_ratio(&self) -> f32 {
+        if self.consonants == 0 {
+            0.0
+        } else {
+            self.vowels as f32 / self.consonants as f32
+        }
+    }
+}
+
+impl PhonologyConfiguration {
+    pub fn stats(&self) -> InventoryStats {
+        let mut stats = InventoryStats {
+            consonants: self.consonants.len(),
+            vowels: self.vowels.len(),
+            by_manner: IndexMap::new(),
+            by_place: IndexMap::new(),
+            voiced: 0,
+            voiceless: 0,
+        };
+
+        for consonant in &self.consonants {
+            let features = &consonant.phoneme;
+            *stats.by_manner.entry(features.manner).or_default() += 1;
+            *stats.by_place.entry(features.place).or_default() += 1;
+            if features.diacritics.contains(&Diacritics::Voiced) {
+                stats.voiced += 1;
+            } else {
+                stats.voiceless += 1;
+            }
+        }
+
+        stats
+    }
+}
+
+impl fmt::Display for InventoryStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{} phonemes: {} consonants, {} vowels (V/C {:.2})",
+            self.total(),
+            self.consonants,
+            self.vowels,
+            self.vowel_consonant_ratio()
+        )?;
+        writeln!(
+            f,
+            "voiced/voiceless: {}/{} ({:.0}% voiced)",
+            self.voiced,
+            self.voiceless,
+            self.voiced_ratio() * 100.0
+        )?;
+        for (manner, count) in &self.by_manner {
+            writeln!(f, "  {:?}: {}", manner, count)?;
+        }
+        for (place, count) in &self.by_place {
+            writeln!(f, "  {:?}: {}", place, count)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::phonology::consonants::pulmonics::{B_BILABIAL, M_BILABIAL, P_BILABIAL, S_ALVEOLAR};
+    use crate::phonology::{A, I, U};
+
+    #[test]
+    fn test_counts_for_small_inventory() {
+        let stats = PhonologyConfiguration::new()
+            .add_consonants(vec![&P_BILABIAL, &B_BILABIAL, &M_BILABIAL, &S_ALVEOLAR])
+            .unwrap()
+            .add_vowels(vec![&A, &I, &U])
+            .unwrap()
+            .stats();
+
+        assert_eq!(stats.total(), 7);
+        assert_eq!(stats.by_manner[&Manner::Plosive], 2);
+        assert_eq!(stats.by_manner[&Manner::Nasal], 1);
+        assert_eq!(stats.by_place[&Place::Labial], 3);
+        assert_eq!(stats.by_place[&Place::Coronal], 1);
+        assert_eq!((stats.voiced, stats.voiceless), (2, 2));
+        assert_eq!(stats.vowel_consonant_ratio(), 0.75);
+        assert!(stats.to_string().starts_with("7 phonemes"));
+    }
+}
@@ -1,5 +1,7 @@
 use crate::define_ipa_phoneme;
-use crate::phonology::{Diacritics, Roundness, TongueHeight, TonguePosition, EMPTY_DIACRITICS};
+use crate::phonology::{
+    phonemes::Vowel, Diacritics, Roundness, TongueHeight, TonguePosition, EMPTY_DIACRITICS,
+};
 use std::borrow::Cow;
 
 // |                | Front
@@ -7,7 +9,7 @@ use std::borrow::Cow;
 // | **Close**      | `i` → `I`             • `y` → `Y`
 // | **Near-close** | `ɪ` → `SMALL_CAP_I`   • `ʏ` → `SMALL_CAP_Y` |
 // | **Close-mid**  | `e` → `E`             • `ø` → `O_WITH_STROKE`
-// | **Mid**        | `e̞` → `MID_E`        • `ø̞` → `O_WITH_STROKE_RAISED`
+// | **Mid**        | `e̞` → `E_RAISED`     • `ø̞` → `O_WITH_STROKE_RAISED`
 // | **Open-mid**   | `ɛ` → `EPSILON`       • `œ` → `OE`
 // | **Near-open**  | `æ` → `AE`            •
 // | **Open**       | `a` → `A`             • `ɶ` → `SMALL_CAP_OE`
@@ -27,11 +29,15 @@ use std::borrow::Cow;
 // | **Close**      | `ɯ` → `TURNED_M`         • `u` → `U`
 // | **Near-close** |                          •`ʊ` → `UPSILON`
 // | **Close-mid**  | `ɤ` → `RAMS_HORN`        • `o` → `O`
-// | **Mid**        | `ɤ̞` → `MID_RAMS_HORN`   • `o̞` → `MID_O`
+// | **Mid**        | `ɤ̞` → `RAMS_HORN_RAISED` • `o̞` → `O_RAISED`
 // | **Open-mid**   | `ʌ` → `TURNED_V`         • `ɔ` → `OPEN_O`
 // | **Near-open**  |
 // | **Open**       | `ɑ` → `SCRIPT_A`         • `ɒ` → `TURNED_SCRIPT_A`
 
+// The mid vowels are written with the lowering mark U+031E and declare
+// `Diacritics::Lowered`. Their `_RAISED` names do not match the mark and
+// are kept so existing code keeps compiling.
+
 define_ipa_phoneme! {
     name: I,
     ipa: "i",
@@ -99,7 +105,7 @@ define_ipa_phoneme! {
         position: TonguePosition::Front,
         height: TongueHeight::Mid,
         roundness: Roundness::Unrounded,
-        diacritics: Cow::Borrowed(&[Diacritics::Raised])
+        diacritics: Cow::Borrowed(&[Diacritics::Lowered])
     }
 }
 define_ipa_phoneme! {
@@ -109,7 +115,7 @@ define_ipa_phoneme! {
         position: TonguePosition::Front,
         height: TongueHeight::Mid,
         roundness: Roundness::Rounded,
-        diacritics: Cow::Borrowed(&[Diacritics::Raised])
+        diacritics: Cow::Borrowed(&[Diacritics::Lowered])
     }
 }
 define_ipa_phoneme! {
@@ -312,7 +318,7 @@ define_ipa_phoneme! {
         position: TonguePosition::Back,
         height: TongueHeight::Mid,
         roundness: Roundness::Rounded,
-        diacritics: Cow::Borrowed(&[Diacritics::Raised])
+        diacritics: Cow::Borrowed(&[Diacritics::Lowered])
     }
 }
 define_ipa_phoneme! {
@@ -322,7 +328,7 @@ define_ipa_phoneme! {
         position: TonguePosition::Back,
         height: TongueHeight::Mid,
         roundness: Roundness::Rounded,
-        diacritics: Cow::Borrowed(&[Diacritics::Raised])
+        diacritics: Cow::Borrowed(&[Diacritics::Lowered])
     }
 }
 define_ipa_phoneme! {
@@ -368,7 +374,7 @@ define_ipa_phoneme! {
 pub fn all_vowels() -> Vec<&'static str> {
     [ALL_VOWELS].concat()
 }
-const ALL_VOWELS: &[&'static str] = &[
+const ALL_VOWELS: &[&str] = &[
     stringify!(I),
     stringify!(Y),
     stringify!(SMALL_CAP_I),
@@ -403,3 +409,55 @@ const ALL_VOWELS: &[&'static str] = &[
     stringify!(SCRIPT_A),
     stringify!(TURNED_SCRIPT_A),
 ];
+
+/// All vowel statics, for lookups by IPA symbol
+pub static VOWEL_PHONEMES: &[&Vowel] = &[
+    &I,
+    &Y,
+    &SMALL_CAP_I,
+    &SMALL_CAP_Y,
+    &E,
+    &O_WITH_STROKE,
+    &E_RAISED,
+    &O_WITH_STROKE_RAISED,
+    &EPSILON,
+    &OE,
+    &AE,
+    &A,
+    &SMALL_CAP_OE,
+    &I_BAR,
+    &U_BAR,
+    &REVERSED_E,
+    &O_BAR,
+    &SCHWA,
+    &REVERSED_EPSILON,
+    &CLOSED_REVERSED_EPSILON,
+    &TURNED_A,
+    &A_CENTRALIZED,
+    &TURNED_M,
+    &U,
+    &UPSILON,
+    &RAMS_HORN,
+    &O,
+    &RAMS_HORN_RAISED,
+    &O_RAISED,
+    &TURNED_V,
+    &OPEN_O,
+    &SCRIPT_A,
+    &TURNED_SCRIPT_A,
+];
+
+pub fn all_vowel_phonemes() -> Vec<&'static Vowel> {
+    VOWEL_PHONEMES.to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_phoneme_registry_matches_names() {
+        let names: Vec<&str> = VOWEL_PHONEMES.iter().map(|v| v.name.as_ref()).collect();
+        assert_eq!(names, ALL_VOWELS);
+    }
+}
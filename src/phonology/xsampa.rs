@@ -0,0 +1,187 @@
+//! X-SAMPA ⇄ IPA conversion
+//!
+//! The table maps single IPA code points (base letters, combining diacritics
+//! and suprasegmentals) to their X-SAMPA spelling, so every phoneme built
+//! from these pieces in `consonants/pulmonics.rs` and `vowels.rs` converts
+//! without its own entry. Characters without an X-SAMPA equivalent (e.g. ⱱ)
+//! are passed through unchanged in both directions.
+
+/// (IPA, X-SAMPA); the first entry wins when converting back to IPA
+const XSAMPA_TABLE: &[(char, &str)] = &[
+    // Nasals
+    ('ɱ', "F"),
+    ('ɳ', "n`"),
+    ('ɲ', "J"),
+    ('ŋ', "N"),
+    ('ɴ', "N\\"),
+    // Plosives
+    ('ʈ', "t`"),
+    ('ɖ', "d`"),
+    ('ɟ', "J\\"),
+    ('ɡ', "g"),
+    ('ɢ', "G\\"),
+    ('ʡ', ">\\"),
+    ('ʔ', "?"),
+    // Fricatives
+    ('ʃ', "S"),
+    ('ʒ', "Z"),
+    ('ʂ', "s`"),
+    ('ʐ', "z`"),
+    ('ɕ', "s\\"),
+    ('ʑ', "z\\"),
+    ('ɸ', "p\\"),
+    ('β', "B"),
+    ('θ', "T"),
+    ('ð', "D"),
+    ('ç', "C"),
+    ('ʝ', "j\\"),
+    ('ɣ', "G"),
+    ('χ', "X"),
+    ('ʁ', "R"),
+    ('ħ', "X\\"),
+    ('ʕ', "?\\"),
+    ('ɦ', "h\\"),
+    // Approximants, taps
+    ('ʋ', "v\\"),
+    ('ɹ', "r\\"),
+    ('ɻ', "r\\`"),
+    ('ɰ', "M\\"),
+    ('ɾ', "4"),
+    // Vowels
+    ('ɪ', "I"),
+    ('ʏ', "Y"),
+    ('ø', "2"),
+    ('ɛ', "E"),
+    ('œ', "9"),
+    ('æ', "{"),
+    ('ɶ', "&"),
+    ('ɨ', "1"),
+    ('ʉ', "}"),
+    ('ɘ', "@\\"),
+    ('ɵ', "8"),
+    ('ə', "@"),
+    ('ɜ', "3"),
+    ('ɞ', "3\\"),
+    ('ɐ', "6"),
+    ('ä', "a_\""),
+    ('ɯ', "M"),
+    ('ʊ', "U"),
+    ('ɤ', "7"),
+    ('ʌ', "V"),
+    ('ɔ', "O"),
+    ('ɑ', "A"),
+    ('ɒ', "Q"),
+    // Diacritics
+    ('\u{0325}', "_0"),  // voiceless (ring below)
+    ('\u{030A}', "_0"),  // voiceless (ring above)
+    ('\u{032A}', "_d"),  // dental
+    ('\u{033C}', "_N"),  // linguolabial
+    ('\u{0320}', "_-"),  // retracted
+    ('\u{031F}', "_+"),  // advanced
+    ('\u{031E}', "_o"),  // lowered
+    ('\u{02D4}', "_r"),  // raised
+    ('\u{031D}', "_r"),  // raised (combining)
+    ('\u{0308}', "_\""), // centralized
+    ('ʷ', "_w"),         // labialized
+    ('ʲ', "_j"),         // palatalized
+    ('ˠ', "_G"),         // velarized
+    ('ˤ', "_?\\"),       // pharyngealized
+    ('ʰ', "_h"),         // aspirated
+    // Suprasegmentals
+    ('ˈ', "\""),
+    ('ˌ', "%"),
+    ('ː', ":"),
+];
+
+/// Converts an IPA string to X-SAMPA
+pub fn ipa_to_xsampa(ipa: &str) -> String {
+    let mut xsampa = String::with_capacity(ipa.len());
+    for c in ipa.chars() {
+        match XSAMPA_TABLE.iter().find(|(symbol, _)| *symbol == c) {
+            Some((_, x)) => xsampa.push_str(x),
+            None => xsampa.push(c),
+        }
+    }
+    xsampa
+}
+
+/// Converts an X-SAMPA string to IPA, always taking the longest matching symbol
+/// (so `r\`` becomes ɻ rather than ɹ followed by a backtick)
+pub fn xsampa_to_ipa(xsampa: &str) -> String {
+    let mut ipa = String::with_capacity(xsampa.len());
+    let mut rest = xsampa;
+
+    while let Some(c) = rest.chars().next() {
+        let mut best: Option<&(char, &str)> = None;
+        for entry in XSAMPA_TABLE {
+            if rest.starts_with(entry.1) && best.is_none_or(|b| entry.1.len() > b.1.len()) {
+                best = Some(entry);
+            }
+        }
+
+        match best {
+            Some((symbol, x)) => {
+                ipa.push(*symbol);
+                rest = &rest[x.len()..];
+            }
+            None => {
+                ipa.push(c);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+    }
+    ipa
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::phonology::consonants::all_consonant_phonemes;
+    use crate::phonology::consonants::pulmonics::{N_VELAR, S_POSTALVEOLAR, TH};
+    use crate::phonology::{all_vowel_phonemes, SCHWA};
+
+    #[test]
+    fn test_single_phonemes_round_trip() {
+        for (phoneme, expected) in [(&S_POSTALVEOLAR, "S"), (&N_VELAR, "N"), (&TH, "T")] {
+            assert_eq!(ipa_to_xsampa(&phoneme.ipa), expected);
+            assert_eq!(xsampa_to_ipa(expected), phoneme.ipa);
+        }
+        assert_eq!(ipa_to_xsampa(&SCHWA.ipa), "@");
+        assert_eq!(xsampa_to_ipa("r\\`"), "ɻ");
+    }
+
+    #[test]
+    fn test_secondary_articulation_and_aspiration() {
+        for (ipa, expected) in [
+            ("kʷ", "k_w"),
+            ("tʲ", "t_j"),
+            ("lˠ", "l_G"),
+            ("sˤ", "s_?\\"),
+            ("pʰ", "p_h"),
+        ] {
+            assert_eq!(ipa_to_xsampa(ipa), expected);
+            assert_eq!(xsampa_to_ipa(expected), ipa);
+        }
+    }
+
+    #[test]
+    fn test_name_round_trip() {
+        let name = "ˈθɔr.valdʃəŋ";
+        let xsampa = ipa_to_xsampa(name);
+        assert_eq!(xsampa, "\"TOr.valdS@N");
+        assert_eq!(xsampa_to_ipa(&xsampa), name);
+    }
+
+    #[test]
+    fn test_all_defined_phonemes_convert_to_ascii() {
+        for ipa in all_consonant_phonemes()
+            .iter()
+            .map(|c| &c.ipa)
+            .chain(all_vowel_phonemes().iter().map(|v| &v.ipa))
+        {
+            // ⱱ has no X-SAMPA symbol
+            let xsampa = ipa_to_xsampa(&ipa.replace('ⱱ', ""));
+            assert!(xsampa.is_ascii(), "{} -> {}", ipa, xsampa);
+        }
+    }
+}
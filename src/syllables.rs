@@ -1,11 +1,17 @@
 pub mod builder;
 pub(crate) mod coda;
 mod errors;
+pub mod gemination;
+pub mod mutation;
 pub(crate) mod nucleus;
 pub(crate) mod onset;
 pub mod patterns;
+pub mod reduplication;
+mod syllabify;
+pub mod tone;
 
 use crate::phonology::phonemes::PhonemeCluster;
+use crate::phonology::{Diacritics, Suprasegmental};
 use crate::syllables::patterns::SyllablePattern;
 use crate::syllables::{
     coda::CodaConfiguration, nucleus::NucleusConfiguration, onset::OnsetConfiguration,
@@ -44,12 +50,118 @@ pub struct SyllableConfiguration<OnsetState = NoOnset, NucleusState = NoNucleus,
 }
 
 /// Represents a complete syllable with all its components
-#[derive(Debug, Clone, PartialEq)]
-pub(crate) struct Syllable {
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Syllable {
     /// Optional onset cluster
     pub onset: Option<PhonemeCluster>,
     /// Required nucleus (vowel core)
     pub nucleus: PhonemeCluster,
     /// Optional coda cluster
     pub coda: Option<PhonemeCluster>,
+    /// Length, tone, nasalization and stress of the nucleus
+    #[serde(default)]
+    pub suprasegmentals: Vec<Suprasegmental>,
+}
+
+impl Syllable {
+    /// Renders the syllable as IPA: stress mark first, nasalization and
+    /// length on the nucleus, tone letters at the end of the syllable
+    pub fn to_ipa(&self) -> String {
+        let has = |mark: Suprasegmental| self.suprasegmentals.contains(&mark);
+        let mut ipa = String::new();
+
+        if has(Suprasegmental::Stressed) {
+            ipa.push('ˈ');
+        } else if has(Suprasegmental::SecondaryStressed) {
+            ipa.push('ˌ');
+        }
+        for phoneme in self.onset.iter().flat_map(|o| o.phonemes.iter()) {
+            ipa.push_str(phoneme);
+        }
+        for phoneme in &self.nucleus.phonemes {
+            ipa.push_str(phoneme);
+            if has(Suprasegmental::Nasalized) {
+                ipa.push('\u{0303}');
+            }
+        }
+        if has(Suprasegmental::Long) {
+            ipa.push_str(Diacritics::Long.ipa_symbol());
+        } else if has(Suprasegmental::HalfLong) {
+            ipa.push('ˑ');
+        }
+        for phoneme in self.coda.iter().flat_map(|c| c.phonemes.iter()) {
+            ipa.push_str(phoneme);
+        }
+        for mark in &self.suprasegmentals {
+            if let Suprasegmental::Tone(level) = mark {
+                ipa.push_str(level.ipa_symbol());
+            }
+        }
+
+        ipa
+    }
+}
+
+impl std::fmt::Display for Syllable {
+    /// Same as `to_ipa`, e.g. `ˈmaːn`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.to_ipa())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::phonology::ToneLevel;
+
+    fn syllable(suprasegmentals: Vec<Suprasegmental>) -> Syllable {
+        Syllable {
+            onset: Some(PhonemeCluster {
+                phonemes: vec!["m".to_string()],
+            }),
+            nucleus: PhonemeCluster {
+                phonemes: vec!["a".to_string()],
+            },
+            coda: Some(PhonemeCluster {
+                phonemes: vec!["n".to_string()],
+            }),
+            suprasegmentals,
+        }
+    }
+
+    #[test]
+    fn test_long_nucleus_renders_length_mark() {
+        assert_eq!(syllable(vec![Suprasegmental::Long]).to_ipa(), "maːn");
+        assert_eq!(syllable(vec![]).to_ipa(), "man");
+    }
+
+    #[test]
+    fn test_stress_nasalization_and_tone() {
+        let marked = syllable(vec![
+            Suprasegmental::Stressed,
+            Suprasegmental::Nasalized,
+            Suprasegmental::Tone(ToneLevel::High),
+            Suprasegmental::Tone(ToneLevel::Low),
+        ]);
+        assert_eq!(marked.to_ipa(), "ˈma\u{0303}n˦˨");
+    }
+
+    #[test]
+    fn test_syllable_json_round_trip() {
+        let long = syllable(vec![Suprasegmental::Long]);
+        let json = serde_json::to_value(&long).unwrap();
+
+        assert_eq!(json["onset"], serde_json::json!(["m"]));
+        assert_eq!(json["nucleus"], serde_json::json!(["a"]));
+        assert_eq!(serde_json::from_value::<Syllable>(json).unwrap(), long);
+    }
+
+    #[test]
+    fn test_display_starts_with_stress_mark() {
+        let stressed = syllable(vec![Suprasegmental::Stressed]);
+        assert!(stressed.to_string().starts_with('ˈ'));
+        let secondary = syllable(vec![Suprasegmental::SecondaryStressed]);
+        assert_eq!(secondary.to_string(), "ˌman");
+        assert_eq!(format!("{}", syllable(vec![])), "man");
+    }
 }
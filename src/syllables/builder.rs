@@ -37,7 +37,7 @@ impl<N, C> SyllableConfiguration<NoOnset, N, C> {
         match onset.validate() {
             Ok(onset) => Ok(SyllableConfiguration {
                 patterns: self.patterns,
-                onset: onset,
+                onset,
                 nucleus: self.nucleus,
                 coda: self.coda,
                 _onset_state: std::marker::PhantomData,
@@ -63,7 +63,7 @@ impl<O, C> SyllableConfiguration<O, NoNucleus, C> {
         word_final_only: Vec<AllowedCluster>,
     ) -> Result<SyllableConfiguration<O, WithNucleus, C>, ValidationErrors> {
         let mut errors = ValidationErrors::new();
-        if (&allowed_phonemes.len() + &allowed_diphthongs.len() + &allowed_triphthongs.len()) < 3 {
+        if (allowed_phonemes.len() + allowed_diphthongs.len() + allowed_triphthongs.len()) < 3 {
             errors.add(
                 "no_enough_vowels",
                 ValidationError::new("phonology_not_enough_vowels")
@@ -1,7 +1,7 @@
 use crate::phonology::phonemes::{AllowedCluster, AllowedPhoneme};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
 pub struct CodaConfiguration {
     pub allowed_phonemes: Vec<AllowedPhoneme>,
     pub allowed_clusters: Vec<AllowedCluster>,
@@ -10,15 +10,6 @@ pub struct CodaConfiguration {
     pub word_final_only: Vec<AllowedCluster>, // Nur am Wortanfang erlaubt
 }
 
-impl Default for CodaConfiguration {
-    fn default() -> Self {
-        Self {
-            allowed_phonemes: Vec::new(),
-            allowed_clusters: Vec::new(),
-            word_final_only: Vec::new(),
-        }
-    }
-}
 
 impl CodaConfiguration {
     pub fn new(
@@ -1,6 +1,6 @@
 use crate::{
     phonology::{
-        all_vowels, consonants::all_consonants, phonemes::AllowedCluster, PhonologyConfiguration,
+        all_vowels, phonemes::AllowedCluster, PhonologyConfiguration,
     },
     syllables::{
         errors::utilities::{
@@ -14,6 +14,76 @@ use crate::{
 };
 
 impl NucleusConfiguration {
+    /// Fail-fast variant of `validate`: stops at the first empty or unknown
+    /// vowel, including the word-position lists, and skips the suggestion search.
+    /// Does not fill the word-position lists.
+    pub fn validate_fast(&self) -> Result<(), ValidationError> {
+        if self.allowed_phonemes.is_empty()
+            && self.allowed_diphthongs.is_empty()
+            && self.allowed_triphthongs.is_empty()
+        {
+            return Err(ValidationError::new("empty_nucleus")
+                .with_message("Nucleus must contain at least one phoneme or cluster"));
+        }
+
+        let vowels = all_vowels();
+        let used = self
+            .allowed_phonemes
+            .iter()
+            .map(|p| p.phoneme.as_str())
+            .chain(
+                self.allowed_diphthongs
+                    .iter()
+                    .flat_map(|d| [d.first.as_str(), d.second.as_str()]),
+            )
+            .chain(
+                self.allowed_triphthongs
+                    .iter()
+                    .flat_map(|t| [t.first.as_str(), t.second.as_str(), t.third.as_str()]),
+            );
+
+        for vowel in used {
+            if !vowels.contains(&vowel) {
+                return Err(create_phoneme_validation_error(
+                    vowel,
+                    &[],
+                    "unknown_vowel",
+                    "Vokal",
+                    None,
+                ));
+            }
+        }
+
+        let word_lists = [
+            (
+                &self.word_initial_only,
+                "word_initial_only",
+                "unknown_vowel_in_word_initial_only",
+            ),
+            (
+                &self.word_final_only,
+                "word_final_only",
+                "unknown_vowel_in_word_final_only",
+            ),
+        ];
+        for (clusters, context, prefix) in word_lists {
+            for (cluster_idx, cluster) in clusters.iter().enumerate() {
+                for (phoneme_idx, phoneme) in cluster.phonemes.iter().enumerate() {
+                    if !vowels.contains(&phoneme.as_str()) {
+                        return Err(create_phoneme_validation_error(
+                            phoneme,
+                            &[],
+                            prefix,
+                            "Vokal",
+                            Some((context, cluster_idx, phoneme_idx)),
+                        ));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
     pub fn validate(&mut self) -> Result<NucleusConfiguration, ValidationErrors> {
         let mut errors = ValidationErrors::new();
 
@@ -94,7 +164,8 @@ impl NucleusConfiguration {
                             }));
                     }
 
-                    _ => unreachable!(),
+                    // Both word-position lists are given, nothing to fill in
+                    _ => {}
                 }
             }
             // allowed_diphthongs_only
@@ -157,7 +228,8 @@ impl NucleusConfiguration {
                             );
                     }
 
-                    _ => unreachable!(),
+                    // Both word-position lists are given, nothing to fill in
+                    _ => {}
                 }
             }
             // allowed_triphthongs_only
@@ -222,7 +294,8 @@ impl NucleusConfiguration {
                             }));
                     }
 
-                    _ => unreachable!(),
+                    // Both word-position lists are given, nothing to fill in
+                    _ => {}
                 }
             }
             // allowed_phonemes_and_diphthongs
@@ -315,7 +388,8 @@ impl NucleusConfiguration {
                             );
                     }
 
-                    _ => unreachable!(),
+                    // Both word-position lists are given, nothing to fill in
+                    _ => {}
                 }
             }
             // allowed_phonemes_and_triphthongs
@@ -410,7 +484,8 @@ impl NucleusConfiguration {
                             }));
                     }
 
-                    _ => unreachable!(),
+                    // Both word-position lists are given, nothing to fill in
+                    _ => {}
                 }
             }
             // allowed_diphthongs_and_triphthongs
@@ -517,7 +592,8 @@ impl NucleusConfiguration {
                             }));
                     }
 
-                    _ => unreachable!(),
+                    // Both word-position lists are given, nothing to fill in
+                    _ => {}
                 }
             }
             // allowed_phonemes_and_diphthongs_and_triphthongs
@@ -656,7 +732,8 @@ impl NucleusConfiguration {
                             }));
                     }
 
-                    _ => unreachable!(),
+                    // Both word-position lists are given, nothing to fill in
+                    _ => {}
                 }
             }
             8..=u8::MAX => unreachable!(),
@@ -814,6 +891,8 @@ impl NucleusConfiguration {
         }
     }
 
+    /// Checks the word-position lists that are set; bit 0 of `word_mask` marks an
+    /// empty `word_initial_only`, bit 1 an empty `word_final_only`
     fn validate_word_mask(&self, word_mask: u8) -> Result<(), ValidationErrors> {
         let mut errors = ValidationErrors::new();
         match &word_mask {
@@ -848,16 +927,13 @@ impl NucleusConfiguration {
             }
             0b01 => {
                 if let Err(e) = validate_clusters_against_list(
-                    &self.word_initial_only,
+                    &self.word_final_only,
                     &all_vowels(),
                     "Vokal",
-                    "unknown_vowel_in_word_initial_only",
-                    "word_initial_only",
+                    "unknown_vowel_in_word_final_only",
+                    "word_final_only",
                     Some(&|cluster_idx, phoneme_idx| {
-                        format!(
-                            "invalid_word_initial_phoneme_{}_{}",
-                            cluster_idx, phoneme_idx
-                        )
+                        format!("invalid_word_final_phoneme_{}_{}", cluster_idx, phoneme_idx)
                     }),
                 ) {
                     errors.merge(e);
@@ -865,13 +941,16 @@ impl NucleusConfiguration {
             }
             0b10 => {
                 if let Err(e) = validate_clusters_against_list(
-                    &self.word_final_only,
+                    &self.word_initial_only,
                     &all_vowels(),
                     "Vokal",
-                    "unknown_vowel_in_word_final_only",
-                    "word_final_only",
+                    "unknown_vowel_in_word_initial_only",
+                    "word_initial_only",
                     Some(&|cluster_idx, phoneme_idx| {
-                        format!("invalid_word_final_phoneme_{}_{}", cluster_idx, phoneme_idx)
+                        format!(
+                            "invalid_word_initial_phoneme_{}_{}",
+                            cluster_idx, phoneme_idx
+                        )
                     }),
                 ) {
                     errors.merge(e);
@@ -888,3 +967,105 @@ impl NucleusConfiguration {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::phonology::phonemes::{AllowedCluster, AllowedPhoneme};
+    use crate::syllables::nucleus::NucleusConfiguration;
+
+    fn word_list(name: &str) -> Vec<AllowedCluster> {
+        if name.is_empty() {
+            return vec![];
+        }
+        vec![AllowedCluster {
+            phonemes: vec![name.to_string()],
+            weight: 1.0,
+        }]
+    }
+
+    fn nucleus(initial: &str, fin: &str) -> NucleusConfiguration {
+        NucleusConfiguration {
+            allowed_phonemes: vec![AllowedPhoneme {
+                phoneme: "A".to_string(),
+                weight: 1.0,
+            }],
+            word_initial_only: word_list(initial),
+            word_final_only: word_list(fin),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_validate_fast_stops_at_first_unknown_vowel() {
+        let nucleus = NucleusConfiguration {
+            allowed_phonemes: ["A", "NOT_A_VOWEL", "ALSO_NOT_A_VOWEL"]
+                .iter()
+                .map(|name| AllowedPhoneme {
+                    phoneme: name.to_string(),
+                    weight: 1.0,
+                })
+                .collect(),
+            ..Default::default()
+        };
+
+        let error = nucleus.validate_fast().unwrap_err();
+        assert_eq!(error.code, "unknown_vowel_no_suggestions");
+        assert_eq!(error.params["attempted_phoneme"], "NOT_A_VOWEL");
+        assert_eq!(
+            NucleusConfiguration::default()
+                .validate_fast()
+                .unwrap_err()
+                .code,
+            "empty_nucleus"
+        );
+    }
+
+    #[test]
+    fn test_validate_fast_checks_word_position_lists() {
+        for (initial, fin, valid) in [
+            ("NOT_A_VOWEL", "", false),
+            ("", "NOT_A_VOWEL", false),
+            ("A", "NOT_A_VOWEL", false),
+            ("E", "", true),
+            ("E", "A", true),
+        ] {
+            let config = nucleus(initial, fin);
+            assert_eq!(config.validate_fast().is_ok(), valid, "{}/{}", initial, fin);
+        }
+
+        let error = nucleus("NOT_A_VOWEL", "").validate_fast().unwrap_err();
+        assert_eq!(
+            error.code,
+            "unknown_vowel_in_word_initial_only_no_suggestions"
+        );
+        assert_eq!(error.params["cluster_index"], "0");
+    }
+
+    #[test]
+    fn test_validate_checks_the_word_position_list_that_is_set() {
+        // validate used to check the empty list instead of the one that was set
+        let errors = nucleus("NOT_A_VOWEL", "").validate().unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|(key, _)| key == "invalid_word_initial_phoneme_0_0"));
+        let errors = nucleus("", "NOT_A_VOWEL").validate().unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|(key, _)| key == "invalid_word_final_phoneme_0_0"));
+
+        // Setting both lists used to panic while filling them in
+        assert!(nucleus("A", "NOT_A_VOWEL").validate().is_err());
+        assert!(nucleus("E", "A").validate().is_ok());
+
+        for (initial, fin) in [("NOT_A_VOWEL", ""), ("E", ""), ("E", "A")] {
+            let mut config = nucleus(initial, fin);
+            assert_eq!(
+                config.validate_fast().is_ok(),
+                config.validate().is_ok(),
+                "{}/{}",
+                initial,
+                fin
+            );
+        }
+    }
+}
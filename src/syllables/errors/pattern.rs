@@ -1,14 +1,13 @@
-use crate::syllables::patterns::{is_valid_phoneme_symbol, is_vowel};
+use crate::syllables::patterns::{is_valid_phoneme_symbol, is_vowel, pattern_error};
 use crate::syllables::SyllablePattern;
 use crate::validation::{ValidationError, ValidationErrors};
 
 impl SyllablePattern {
     pub fn validate_pattern(&self) -> Result<(), ValidationErrors> {
         let mut errors = ValidationErrors::new();
-        let mut temp_groups: Vec<String> = Vec::new();
 
-        // Initialize components
-        let mut pattern = String::new();
+        // Phoneme symbols that make up the pattern, with their position in the schema
+        let mut symbols: Vec<(usize, char)> = Vec::new();
 
         if self.schema.is_empty() {
             errors.add(
@@ -18,48 +17,90 @@ impl SyllablePattern {
             );
         }
 
-        if self.schema.contains('(') || self.schema.contains(')') {
-            let open_paren_count = self.schema.matches('(').count();
-            let close_paren_count = self.schema.matches(')').count();
+        let explicit = self.schema.contains('(') || self.schema.contains(')');
+        let mut open_at: Option<usize> = None;
+        let mut group_len = 0;
 
-            if open_paren_count != close_paren_count {
-                errors.add(
-                    "mismatched_parentheses",
-                    ValidationError::new("mismatched_parentheses")
-                        .with_message("Mismatched parentheses in pattern"),
-                );
-            }
-
-            self.schema.split('(').skip(1).for_each(|group| {
-                if group.is_empty() || group == ")" {
-                    errors.add(
+        for (position, ch) in self.schema.chars().enumerate() {
+            match ch {
+                '(' => {
+                    if open_at.is_some() {
+                        errors.add(
+                            "mismatched_parentheses",
+                            pattern_error(
+                                "mismatched_parentheses",
+                                "nested_group",
+                                "Nested '(' in pattern",
+                                &self.schema,
+                                position,
+                            ),
+                        );
+                    }
+                    open_at = Some(position);
+                    group_len = 0;
+                }
+                ')' => match open_at.take() {
+                    None => errors.add(
+                        "mismatched_parentheses",
+                        pattern_error(
+                            "mismatched_parentheses",
+                            "unmatched_close",
+                            "Unmatched ')' in pattern",
+                            &self.schema,
+                            position,
+                        ),
+                    ),
+                    Some(start) if group_len == 0 => errors.add(
                         "empty_group",
-                        ValidationError::new("empty_group")
-                            .with_message("Empty group () not allowed"),
-                    );
+                        pattern_error(
+                            "empty_group",
+                            "empty_group",
+                            "Empty group () not allowed",
+                            &self.schema,
+                            start,
+                        ),
+                    ),
+                    Some(_) => {}
+                },
+                _ => {
+                    // In explicit mode only the contents of groups count
+                    if !explicit || open_at.is_some() {
+                        symbols.push((position, ch));
+                        group_len += 1;
+                    }
                 }
-                temp_groups.push(group.split(')').next().unwrap().to_string());
-            });
-
-            for group in &temp_groups {
-                pattern.push_str(group);
             }
-        } else {
-            pattern = self.schema.clone();
         }
 
-        if let Some(ch) = pattern.chars().find(|&ch| !is_valid_phoneme_symbol(ch)) {
+        if let Some(start) = open_at {
+            errors.add(
+                "mismatched_parentheses",
+                pattern_error(
+                    "mismatched_parentheses",
+                    "unclosed_group",
+                    "Unclosed '(' in pattern",
+                    &self.schema,
+                    start,
+                ),
+            );
+        }
+
+        if let Some(&(position, ch)) = symbols.iter().find(|(_, ch)| !is_valid_phoneme_symbol(*ch))
+        {
             errors.add(
                 "invalid_phoneme",
-                ValidationError::new("invalid_phoneme").with_message(format!(
-                    "Pattern contains invalid phonemes symbol: {:?}",
-                    ch
-                )),
+                pattern_error(
+                    "invalid_phoneme",
+                    "invalid_symbol",
+                    &format!("Pattern contains invalid phonemes symbol: {:?}", ch),
+                    &self.schema,
+                    position,
+                )
+                .add_param("symbol", format!("{:?}", ch)),
             );
         }
 
-        let nucleus_start = pattern.chars().position(is_vowel);
-        if nucleus_start.is_none() {
+        if !symbols.iter().any(|(_, ch)| is_vowel(*ch)) {
             errors.add(
                 "no_nucleus",
                 ValidationError::new("no_nucleus")
@@ -75,3 +116,40 @@ impl SyllablePattern {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validation::ValidationErrorsKind;
+
+    fn first_error(errors: &ValidationErrors, field: &str) -> ValidationError {
+        match errors.get_field_errors(field) {
+            Some(ValidationErrorsKind::Struct(errs)) => errs[0].clone(),
+            _ => panic!("expected {} error", field),
+        }
+    }
+
+    #[test]
+    fn test_unclosed_group_reports_position() {
+        let errors = SyllablePattern::new("(C)(VC", 1.0)
+            .validate_pattern()
+            .unwrap_err();
+
+        let error = first_error(&errors, "mismatched_parentheses");
+        assert_eq!(error.params["position"], 3);
+        assert_eq!(
+            error.message.as_deref(),
+            Some("Unclosed '(' in pattern at position 3:\n  (C)(VC\n     ^")
+        );
+    }
+
+    #[test]
+    fn test_invalid_symbol_reports_position() {
+        let errors = SyllablePattern::new("CVx", 1.0)
+            .validate_pattern()
+            .unwrap_err();
+
+        let error = first_error(&errors, "invalid_phoneme");
+        assert_eq!(error.params["position"], 2);
+    }
+}
@@ -1,7 +1,9 @@
 use crate::{
     phonology::PhonologyConfiguration,
-    syllables::{SyllableConfiguration, WithCoda, WithNucleus, WithOnset},
-    validation::ValidationErrors,
+    syllables::{
+        patterns::weight_in_range, SyllableConfiguration, WithCoda, WithNucleus, WithOnset,
+    },
+    validation::{ValidationError, ValidationErrors},
 };
 
 impl<OnsetState, NucleusState, CodaState>
@@ -25,6 +27,10 @@ impl<OnsetState, NucleusState, CodaState>
             errors.merge(e);
         }
 
+        if let Err(e) = self.validate_pattern_weights() {
+            errors.merge(e);
+        }
+
         if errors.is_empty() {
             Ok(SyllableConfiguration {
                 patterns: self.patterns.clone(),
@@ -39,4 +45,151 @@ impl<OnsetState, NucleusState, CodaState>
             Err(errors)
         }
     }
+
+    /// Every pattern weight must lie in (0.0, 1.0] so that each pattern can be
+    /// drawn; a set without any positive weight cannot be sampled at all
+    pub fn validate_pattern_weights(&self) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+
+        for pattern in &self.patterns {
+            if pattern.weight <= 0.0 || !weight_in_range(pattern.weight) {
+                errors.add(
+                    format!("pattern_{}", pattern.schema),
+                    ValidationError::new("unusable_pattern_weights")
+                        .add_param("schema", pattern.schema.clone())
+                        .add_param("weight", pattern.weight)
+                        .add_param("reason", "weight_out_of_range")
+                        .with_message(format!(
+                            "Gewicht {} von Pattern '{}' liegt nicht in (0.0, 1.0]",
+                            pattern.weight, pattern.schema
+                        )),
+                );
+            }
+        }
+
+        if !self.patterns.is_empty() && !self.patterns.iter().any(|p| p.weight > 0.0) {
+            errors.add(
+                "patterns",
+                ValidationError::new("unusable_pattern_weights")
+                    .add_param("reason", "no_positive_weight")
+                    .with_message("Kein Pattern hat ein positives Gewicht"),
+            );
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Non-blocking check for onset/nucleus/coda entries with weight 0.0: they
+    /// can never be drawn and are most likely a configuration mistake.
+    /// Returns one `unreachable_cluster` warning per entry.
+    pub fn unreachable_cluster_warnings(&self) -> ValidationErrors {
+        let onset = self
+            .onset
+            .allowed_phonemes
+            .iter()
+            .map(|p| (vec![p.phoneme.clone()], p.weight))
+            .chain(
+                self.onset
+                    .allowed_clusters
+                    .iter()
+                    .map(|c| (c.phonemes.clone(), c.weight)),
+            )
+            .map(|entry| ("onset", entry));
+        let nucleus = self
+            .nucleus
+            .allowed_phonemes
+            .iter()
+            .map(|p| (vec![p.phoneme.clone()], p.weight))
+            .chain(
+                self.nucleus
+                    .allowed_diphthongs
+                    .iter()
+                    .map(|d| (vec![d.first.clone(), d.second.clone()], d.weight)),
+            )
+            .chain(self.nucleus.allowed_triphthongs.iter().map(|t| {
+                (
+                    vec![t.first.clone(), t.second.clone(), t.third.clone()],
+                    t.weight,
+                )
+            }))
+            .map(|entry| ("nucleus", entry));
+        let coda = self
+            .coda
+            .allowed_phonemes
+            .iter()
+            .map(|p| (vec![p.phoneme.clone()], p.weight))
+            .chain(
+                self.coda
+                    .allowed_clusters
+                    .iter()
+                    .map(|c| (c.phonemes.clone(), c.weight)),
+            )
+            .map(|entry| ("coda", entry));
+
+        let mut warnings = ValidationErrors::new();
+        for (position, (phonemes, weight)) in onset.chain(nucleus).chain(coda) {
+            if weight != 0.0 {
+                continue;
+            }
+            let joined = phonemes.join("+");
+            warnings.add(
+                format!("{}_{}", position, joined),
+                ValidationError::new("unreachable_cluster")
+                    .add_param("position", position)
+                    .add_param("phonemes", joined.clone())
+                    .with_message(format!(
+                        "{} '{}' hat Gewicht 0.0 und wird nie gewählt",
+                        position, joined
+                    )),
+            );
+        }
+        warnings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_all_zero_weights_are_rejected() {
+        let config = SyllableConfiguration::new()
+            .add_pattern("CV", 0.0)
+            .unwrap()
+            .add_pattern("CVC", 0.0)
+            .unwrap();
+
+        let errors = config.validate_pattern_weights().unwrap_err();
+        assert!(errors.has_error("patterns"));
+        assert!(errors.has_error("pattern_CV"));
+        assert_eq!(errors.filter_by_code("unusable_pattern_weights").len(), 3);
+
+        let usable = SyllableConfiguration::new().add_pattern("CV", 0.5).unwrap();
+        assert!(usable.validate_pattern_weights().is_ok());
+    }
+
+    #[test]
+    fn test_zero_weight_onset_warns_once() {
+        use crate::phonology::phonemes::AllowedPhoneme;
+
+        let phoneme = |name: &str, weight: f32| AllowedPhoneme {
+            phoneme: name.to_string(),
+            weight,
+        };
+        let config = SyllableConfiguration::new()
+            .set_onset(
+                vec![phoneme("T_ALVEOLAR", 0.0), phoneme("K_VELAR", 1.0)],
+                vec![],
+                vec![],
+            )
+            .unwrap();
+
+        let warnings = config.unreachable_cluster_warnings();
+        assert_eq!(warnings.total_count(), 1);
+        assert!(warnings.has_error("onset_T_ALVEOLAR"));
+    }
 }
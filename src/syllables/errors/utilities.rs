@@ -64,7 +64,11 @@ pub(crate) fn create_phoneme_validation_error(
             .add_param("suggestions", suggestions.join(", "))
             .add_param("suggestion_count", suggestions.len().to_string())
             .add_param("similarity_algorithm", "levenshtein_distance")
-            .with_message(format!("{}. Meinten Sie: {:?}", base_message, suggestions));
+            .with_message(format!(
+                "{}. Meinten Sie: {}",
+                base_message,
+                suggestions.join(", ")
+            ));
 
         if let Some((_, cluster_idx, phoneme_idx)) = context {
             error = error
@@ -0,0 +1,136 @@
+//! Handling of identical consonants across a syllable boundary (…t.t…)
+
+use crate::phonology::Diacritics;
+use crate::syllables::Syllable;
+use serde::{Deserialize, Serialize};
+
+/// What to do when a coda ends in the same consonant the next onset starts with
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum GeminationPolicy {
+    /// Leave the geminate as is: `at.ta`
+    #[default]
+    Keep,
+    /// Drop the coda copy: `a.ta`
+    Collapse,
+    /// Drop the coda copy and mark the onset consonant long: `a.tːa`
+    Lengthen,
+}
+
+impl GeminationPolicy {
+    /// Applies the policy to every boundary in `syllables`
+    pub fn apply(&self, syllables: &mut [Syllable]) {
+        if *self == GeminationPolicy::Keep {
+            return;
+        }
+
+        for i in 1..syllables.len() {
+            let (before, after) = syllables.split_at_mut(i);
+            let (previous, next) = (&mut before[i - 1], &mut after[0]);
+
+            let (Some(coda), Some(onset)) = (previous.coda.as_mut(), next.onset.as_mut()) else {
+                continue;
+            };
+            // Empty clusters have no consonant to geminate
+            let (Some(last), Some(first)) = (coda.phonemes.last(), onset.phonemes.first()) else {
+                continue;
+            };
+            if last != first {
+                continue;
+            }
+
+            coda.phonemes.pop();
+            if coda.phonemes.is_empty() {
+                previous.coda = None;
+            }
+
+            if *self == GeminationPolicy::Lengthen {
+                onset.phonemes[0].push_str(Diacritics::Long.ipa_symbol());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::phonology::phonemes::PhonemeCluster;
+
+    fn cluster(phonemes: &[&str]) -> PhonemeCluster {
+        PhonemeCluster {
+            phonemes: phonemes.iter().map(|p| p.to_string()).collect(),
+        }
+    }
+
+    /// at.ta
+    fn geminate() -> Vec<Syllable> {
+        vec![
+            Syllable {
+                onset: None,
+                nucleus: cluster(&["a"]),
+                coda: Some(cluster(&["t"])),
+                suprasegmentals: Vec::new(),
+            },
+            Syllable {
+                onset: Some(cluster(&["t"])),
+                nucleus: cluster(&["a"]),
+                coda: None,
+                suprasegmentals: Vec::new(),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_keep_leaves_geminate() {
+        let mut syllables = geminate();
+        GeminationPolicy::Keep.apply(&mut syllables);
+        assert_eq!(syllables, geminate());
+    }
+
+    #[test]
+    fn test_collapse_drops_coda_copy() {
+        let mut syllables = geminate();
+        GeminationPolicy::Collapse.apply(&mut syllables);
+        assert_eq!(syllables[0].coda, None);
+        assert_eq!(syllables[1].onset, Some(cluster(&["t"])));
+    }
+
+    #[test]
+    fn test_lengthen_marks_surviving_consonant() {
+        let mut syllables = geminate();
+        GeminationPolicy::Lengthen.apply(&mut syllables);
+        assert_eq!(syllables[0].coda, None);
+        assert_eq!(syllables[1].onset, Some(cluster(&["tː"])));
+    }
+
+    #[test]
+    fn test_empty_clusters_are_skipped() {
+        let boundary = |coda: &[&str], onset: &[&str]| {
+            vec![
+                Syllable {
+                    onset: None,
+                    nucleus: cluster(&["a"]),
+                    coda: Some(cluster(coda)),
+                    suprasegmentals: Vec::new(),
+                },
+                Syllable {
+                    onset: Some(cluster(onset)),
+                    nucleus: cluster(&["a"]),
+                    coda: None,
+                    suprasegmentals: Vec::new(),
+                },
+            ]
+        };
+
+        for policy in [
+            GeminationPolicy::Keep,
+            GeminationPolicy::Collapse,
+            GeminationPolicy::Lengthen,
+        ] {
+            for (coda, onset) in [(&[][..], &["t"][..]), (&["t"], &[]), (&[], &[])] {
+                let mut syllables = boundary(coda, onset);
+                policy.apply(&mut syllables);
+                assert_eq!(syllables, boundary(coda, onset), "{:?}", policy);
+            }
+        }
+    }
+}
@@ -0,0 +1,136 @@
+//! Vowel mutation (umlaut, ablaut) for inflected variants of a word
+
+use crate::phonology::phonemes::VowelConfiguration;
+use crate::phonology::vowels::all_vowel_phonemes;
+use crate::phonology::{
+    PhonologyConfiguration, Roundness, Suprasegmental, TongueHeight, TonguePosition,
+};
+use crate::syllables::Syllable;
+use crate::validation::ValidationError;
+use serde::{Deserialize, Serialize};
+
+/// Systematic change of vowel features, e.g. fronting for umlaut (`u` → `y`)
+/// or lowering for ablaut (`i` → `a`). Features left as `None` stay unchanged.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct VowelMutation {
+    pub position: Option<TonguePosition>,
+    pub height: Option<TongueHeight>,
+    pub roundness: Option<Roundness>,
+}
+
+impl VowelMutation {
+    fn matches(&self, source: &VowelConfiguration, candidate: &VowelConfiguration) -> bool {
+        candidate.position == self.position.unwrap_or(source.position)
+            && candidate.height == self.height.unwrap_or(source.height)
+            && candidate.roundness == self.roundness.unwrap_or(source.roundness)
+    }
+
+    /// Mutates the nucleus vowels of the stressed syllable (or the first
+    /// syllable if none is stressed). Every target must be part of the phonology;
+    /// otherwise nothing is changed and the first missing vowel is reported.
+    pub fn apply(
+        &self,
+        syllables: &mut [Syllable],
+        phonology: &PhonologyConfiguration,
+    ) -> Result<(), ValidationError> {
+        let Some(index) = syllables
+            .iter()
+            .position(|s| s.suprasegmentals.contains(&Suprasegmental::Stressed))
+            .or((!syllables.is_empty()).then_some(0))
+        else {
+            return Ok(());
+        };
+
+        let mut mutated = syllables[index].nucleus.phonemes.clone();
+        for token in &mut mutated {
+            let Some(source) = phonology.vowels.iter().find(|v| v.ipa == token.as_str()) else {
+                continue;
+            };
+            let source = &source.phoneme;
+
+            match phonology
+                .vowels
+                .iter()
+                .find(|v| self.matches(source, &v.phoneme))
+            {
+                Some(target) => *token = target.ipa.to_string(),
+                None => {
+                    let attempted = all_vowel_phonemes()
+                        .into_iter()
+                        .find(|v| self.matches(source, &v.phoneme))
+                        .map(|v| v.ipa.to_string())
+                        .unwrap_or_else(|| format!("{:?}", self));
+                    return Err(ValidationError::new("vowel_not_in_phonology")
+                        .add_param("attempted_phoneme", attempted.clone())
+                        .with_message(format!(
+                            "Vokal '{}' ist nicht in der Phonologie enthalten",
+                            attempted
+                        )));
+                }
+            }
+        }
+
+        syllables[index].nucleus.phonemes = mutated;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::phonology::phonemes::PhonemeCluster;
+    use crate::phonology::vowels::{A, EPSILON, U};
+
+    fn syllable(onset: &str, nucleus: &str, stressed: bool) -> Syllable {
+        let cluster = |ipa: &str| PhonemeCluster {
+            phonemes: vec![ipa.to_string()],
+        };
+        Syllable {
+            onset: Some(cluster(onset)),
+            nucleus: cluster(nucleus),
+            coda: None,
+            suprasegmentals: if stressed {
+                vec![Suprasegmental::Stressed]
+            } else {
+                Vec::new()
+            },
+        }
+    }
+
+    fn phonology() -> PhonologyConfiguration {
+        PhonologyConfiguration::new()
+            .add_vowels(vec![&A, &EPSILON, &U])
+            .unwrap()
+    }
+
+    #[test]
+    fn test_height_mutation_hits_stressed_nucleus() {
+        let raising = VowelMutation {
+            height: Some(TongueHeight::OpenMid),
+            ..Default::default()
+        };
+        let mut word = vec![syllable("m", "a", false), syllable("n", "a", true)];
+        raising.apply(&mut word, &phonology()).unwrap();
+
+        assert_eq!(word[0].nucleus.phonemes, vec!["a"]);
+        assert_eq!(word[1].nucleus.phonemes, vec!["ɛ"]);
+    }
+
+    #[test]
+    fn test_fronting_requires_target_in_inventory() {
+        let fronting = VowelMutation {
+            position: Some(TonguePosition::Front),
+            ..Default::default()
+        };
+        let mut word = vec![syllable("m", "u", false)];
+        let error = fronting.apply(&mut word, &phonology()).unwrap_err();
+
+        assert_eq!(error.code, "vowel_not_in_phonology");
+        assert_eq!(error.params["attempted_phoneme"], "y");
+        assert_eq!(
+            error.message.as_deref(),
+            Some("Vokal 'y' ist nicht in der Phonologie enthalten")
+        );
+        assert_eq!(word[0].nucleus.phonemes, vec!["u"]);
+    }
+}
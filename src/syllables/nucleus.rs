@@ -3,7 +3,7 @@ use crate::phonology::phonemes::{
 };
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
 pub struct NucleusConfiguration {
     pub allowed_phonemes: Vec<AllowedPhoneme>,
     pub allowed_diphthongs: Vec<AllowedDiphthong>,
@@ -14,17 +14,6 @@ pub struct NucleusConfiguration {
     pub word_final_only: Vec<AllowedCluster>,   // Nur am Wortanfang erlaubt
 }
 
-impl Default for NucleusConfiguration {
-    fn default() -> Self {
-        Self {
-            allowed_phonemes: Vec::new(),
-            allowed_diphthongs: Vec::new(),
-            allowed_triphthongs: Vec::new(),
-            word_initial_only: Vec::new(), // Nur am Wortanfang erlaubt
-            word_final_only: Vec::new(),
-        }
-    }
-}
 impl NucleusConfiguration {
     pub fn new(
         allowed_phonemes: Vec<AllowedPhoneme>,
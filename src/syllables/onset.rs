@@ -1,7 +1,7 @@
 use crate::phonology::phonemes::{AllowedCluster, AllowedPhoneme};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
 pub struct OnsetConfiguration {
     pub allowed_phonemes: Vec<AllowedPhoneme>,
     pub allowed_clusters: Vec<AllowedCluster>,
@@ -10,15 +10,6 @@ pub struct OnsetConfiguration {
     pub word_initial_only: Vec<AllowedCluster>, // Nur am Wortanfang erlaubt
 }
 
-impl Default for OnsetConfiguration {
-    fn default() -> Self {
-        Self {
-            allowed_phonemes: Vec::new(),
-            allowed_clusters: Vec::new(),
-            word_initial_only: Vec::new(),
-        }
-    }
-}
 impl OnsetConfiguration {
     pub fn new(
         allowed_phonemes: Vec<AllowedPhoneme>,
@@ -24,7 +24,27 @@ pub fn is_valid_phoneme_symbol(ch: char) -> bool {
 }
 
 pub fn weight_in_range(weight: f32) -> bool {
-    weight >= 0.0 && weight <= 1.0
+    (0.0..=1.0).contains(&weight)
+}
+
+/// Error pointing at `position` (in chars) of `schema`, with a caret-annotated snippet.
+/// `reason` tells apart the different failures sharing one `code`.
+pub(crate) fn pattern_error(
+    code: &'static str,
+    reason: &'static str,
+    message: &str,
+    schema: &str,
+    position: usize,
+) -> ValidationError {
+    let snippet = format!("  {}\n  {}^", schema, " ".repeat(position));
+    ValidationError::new(code)
+        .add_param("reason", reason)
+        .add_param("position", position)
+        .add_param("snippet", snippet.clone())
+        .with_message(format!(
+            "{} at position {}:\n{}",
+            message, position, snippet
+        ))
 }
 
 /// Parsed syllable pattern with onset/nucleus/coda distinction
@@ -137,10 +157,12 @@ impl SyllablePattern {
                     let mut errors = ValidationErrors::new();
                     errors.add(
                         "invalid_character",
-                        ValidationError::new("invalid_character").with_message(format!(
-                            "Invalid character '{}' (only C, c, V, v allowed)",
-                            ch
-                        )),
+                        ValidationError::new("invalid_character")
+                            .add_param("character", ch.to_string())
+                            .with_message(format!(
+                                "Invalid character '{}' (only C, c, V, v allowed)",
+                                ch
+                            )),
                     );
                     return Err(errors);
                 }
@@ -192,6 +214,7 @@ impl SyllablePattern {
                 errors.add(
                     "invalid_character",
                     ValidationError::new("invalid_character")
+                        .add_param("reason", "unclassified_group")
                         .with_message("Invalid character '?' found"),
                 );
                 return Err(errors);
@@ -222,75 +245,71 @@ impl SyllablePattern {
     fn extract_groups(pattern: &str) -> Result<Vec<String>, ValidationErrors> {
         let mut groups = Vec::new();
         let mut current_group = String::new();
-        let mut in_group = false;
-        let mut paren_count = 0;
+        let mut open_at: Option<usize> = None;
 
-        for ch in pattern.chars() {
+        let fail = |code: &'static str, reason: &'static str, message: &str, position: usize| {
+            let mut errors = ValidationErrors::new();
+            errors.add(code, pattern_error(code, reason, message, pattern, position));
+            Err(errors)
+        };
+
+        for (position, ch) in pattern.chars().enumerate() {
             match ch {
                 '(' => {
-                    if in_group {
-                        let mut errors = ValidationErrors::new();
-                        errors.add(
+                    if open_at.is_some() {
+                        return fail(
                             "mismatched_parentheses",
-                            ValidationError::new("mismatched_parentheses")
-                                .with_message("Mismatched parentheses in pattern"),
+                            "nested_group",
+                            "Nested '(' in pattern",
+                            position,
                         );
-                        return Err(errors);
                     }
-                    in_group = true;
-                    paren_count += 1;
+                    open_at = Some(position);
                     current_group.clear();
                 }
                 ')' => {
-                    if !in_group {
-                        let mut errors = ValidationErrors::new();
-                        errors.add(
+                    let Some(start) = open_at.take() else {
+                        return fail(
                             "mismatched_parentheses",
-                            ValidationError::new("mismatched_parentheses")
-                                .with_message("Mismatched parentheses in pattern"),
+                            "unmatched_close",
+                            "Unmatched ')' in pattern",
+                            position,
                         );
-                        return Err(errors);
-                    }
+                    };
                     if current_group.is_empty() {
-                        let mut errors = ValidationErrors::new();
-                        errors.add(
+                        return fail(
                             "empty_group",
-                            ValidationError::new("empty_group")
-                                .with_message("Empty group () not allowed"),
+                            "empty_group",
+                            "Empty group () not allowed",
+                            start,
                         );
-                        return Err(errors);
                     }
                     groups.push(current_group.clone());
                     current_group.clear();
-                    in_group = false;
-                    paren_count -= 1;
                 }
                 _ => {
-                    if in_group {
+                    if open_at.is_some() {
                         current_group.push(ch);
                     } else {
                         // Characters outside parentheses not allowed in explicit mode
-                        let mut errors = ValidationErrors::new();
-                        errors.add(
+                        return fail(
                             "mismatched_parentheses",
-                            ValidationError::new("mismatched_parentheses").with_message(
-                                "Characters outside parentheses not allowed in explicit mode",
-                            ),
+                            "outside_group",
+                            "Characters outside parentheses not allowed in explicit mode",
+                            position,
                         );
-                        return Err(errors);
                     }
                 }
             }
         }
 
-        if paren_count != 0 {
-            let mut errors = ValidationErrors::new();
-            errors.add(
+        if let Some(start) = open_at {
+            return fail(
                 "mismatched_parentheses",
-                ValidationError::new("mismatched_parentheses")
-                    .with_message("Mismatched parentheses in pattern"),
+                "unclosed_group",
+                "Unclosed '(' in pattern",
+                start,
             );
-            return Err(errors);
         }
 
         Ok(groups)
@@ -0,0 +1,117 @@
+//! Reduplication of syllables (mama, ba-bala)
+
+use crate::phonology::Suprasegmental;
+use crate::syllables::Syllable;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReduplicationKind {
+    /// Copies the whole word: `bala` → `balabala`
+    Full,
+    /// Copies onset and nucleus of the first syllable: `bala` → `babala`
+    InitialCv,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ReduplicationRule {
+    pub kind: ReduplicationKind,
+    /// Chance (0.0..=1.0) that a word is reduplicated at all; values outside
+    /// the range are clamped and NaN or infinite values count as 0.0
+    pub probability: f32,
+}
+
+impl ReduplicationRule {
+    pub fn new(kind: ReduplicationKind, probability: f32) -> Self {
+        Self { kind, probability }
+    }
+
+    /// Prepends the reduplicant to `syllables` with the rule's probability.
+    /// The copy carries no stress mark, so the base keeps the word's stress.
+    pub fn apply<R: Rng + ?Sized>(&self, syllables: &mut Vec<Syllable>, rng: &mut R) {
+        // gen_bool panics on NaN, which clamp passes through
+        let probability = if self.probability.is_finite() {
+            self.probability.clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        if syllables.is_empty() || !rng.gen_bool(probability as f64) {
+            return;
+        }
+
+        let mut copy: Vec<Syllable> = match self.kind {
+            ReduplicationKind::Full => syllables.clone(),
+            ReduplicationKind::InitialCv => vec![Syllable {
+                coda: None,
+                ..syllables[0].clone()
+            }],
+        };
+        for syllable in &mut copy {
+            syllable
+                .suprasegmentals
+                .retain(|mark| *mark != Suprasegmental::Stressed);
+        }
+
+        syllables.splice(0..0, copy);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::phonology::phonemes::PhonemeCluster;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn syllable(onset: &str, nucleus: &str, coda: Option<&str>) -> Syllable {
+        let cluster = |ipa: &str| PhonemeCluster {
+            phonemes: vec![ipa.to_string()],
+        };
+        Syllable {
+            onset: Some(cluster(onset)),
+            nucleus: cluster(nucleus),
+            coda: coda.map(cluster),
+            suprasegmentals: Vec::new(),
+        }
+    }
+
+    fn render(syllables: &[Syllable]) -> String {
+        syllables.iter().map(Syllable::to_ipa).collect()
+    }
+
+    #[test]
+    fn test_full_reduplication_doubles_word() {
+        let base = vec![syllable("b", "a", None), syllable("l", "a", Some("n"))];
+        let mut word = base.clone();
+        ReduplicationRule::new(ReduplicationKind::Full, 1.0)
+            .apply(&mut word, &mut ChaCha8Rng::seed_from_u64(1));
+
+        assert_eq!(word.len(), 2 * base.len());
+        assert_eq!(word[..2], word[2..]);
+        assert_eq!(render(&word), "balanbalan");
+    }
+
+    #[test]
+    fn test_initial_cv_and_probability() {
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+
+        let mut word = vec![syllable("b", "a", Some("l"))];
+        ReduplicationRule::new(ReduplicationKind::InitialCv, 1.0).apply(&mut word, &mut rng);
+        assert_eq!(render(&word), "babal");
+
+        let mut word = vec![syllable("b", "a", None)];
+        ReduplicationRule::new(ReduplicationKind::Full, 0.0).apply(&mut word, &mut rng);
+        assert_eq!(word.len(), 1);
+    }
+
+    #[test]
+    fn test_non_finite_probability_never_reduplicates() {
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+
+        for probability in [f32::NAN, f32::INFINITY, f32::NEG_INFINITY] {
+            let mut word = vec![syllable("b", "a", None)];
+            ReduplicationRule::new(ReduplicationKind::Full, probability).apply(&mut word, &mut rng);
+            assert_eq!(word.len(), 1);
+        }
+    }
+}
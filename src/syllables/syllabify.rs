@@ -0,0 +1,360 @@
+//! Syllabification of IPA token sequences (Maximal Onset Principle)
+
+use crate::phonology::phonemes::PhonemeCluster;
+use crate::phonology::PhonologyConfiguration;
+use crate::syllables::{Syllable, SyllableConfiguration};
+use crate::validation::{ValidationError, ValidationErrors};
+use std::ops::Range;
+
+/// Length marks that may follow a phoneme without changing its identity
+const LENGTH_MARKS: [char; 2] = ['ː', 'ˑ'];
+
+/// Phoneme name and vowel flag of the phonology entry `token` spells exactly,
+/// ignoring trailing length marks. Tokens with any other extra mark (tʰ, t͡ʃ)
+/// do not resolve to their base phoneme.
+fn lookup<'a>(token: &str, phonology: &'a PhonologyConfiguration) -> Option<(&'a str, bool)> {
+    let bare = token.trim_end_matches(LENGTH_MARKS);
+    phonology
+        .consonants
+        .iter()
+        .map(|c| (c.ipa.as_ref(), c.name.as_ref(), false))
+        .chain(
+            phonology
+                .vowels
+                .iter()
+                .map(|v| (v.ipa.as_ref(), v.name.as_ref(), true)),
+        )
+        .find(|(ipa, _, _)| *ipa == token || *ipa == bare)
+        .map(|(_, name, is_vowel)| (name, is_vowel))
+}
+
+fn cluster(tokens: &[String]) -> Option<PhonemeCluster> {
+    if tokens.is_empty() {
+        None
+    } else {
+        Some(PhonemeCluster {
+            phonemes: tokens.to_vec(),
+        })
+    }
+}
+
+impl<O, N, C> SyllableConfiguration<O, N, C> {
+    /// Splits IPA tokens (see `phonology::ipa::tokenize`) into syllables.
+    ///
+    /// Consonants between two nuclei go to the following onset as far as the
+    /// onset configuration allows; the rest stays in the preceding coda.
+    /// Adjacent vowels share a nucleus only if they form an allowed diphthong
+    /// or triphthong. Syllables hold the IPA tokens; an input without any
+    /// vowel yields no syllables. Every token must be a phoneme of the
+    /// phonology, otherwise a `phoneme_not_found` error is reported per token.
+    pub fn syllabify(
+        &self,
+        tokens: &[String],
+        phonology: &PhonologyConfiguration,
+    ) -> Result<Vec<Syllable>, ValidationErrors> {
+        let phonemes: Vec<Option<(&str, bool)>> =
+            tokens.iter().map(|t| lookup(t, phonology)).collect();
+
+        let mut errors = ValidationErrors::new();
+        for (index, token) in tokens.iter().enumerate() {
+            if phonemes[index].is_none() {
+                errors.add(
+                    format!("token_{}", index),
+                    ValidationError::new("phoneme_not_found")
+                        .add_param("phoneme", token.clone())
+                        .add_param("position", index)
+                        .with_message(format!("Phonem nicht gefunden: {}", token)),
+                );
+            }
+        }
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        let names: Vec<Option<&str>> = phonemes.iter().map(|p| p.map(|(n, _)| n)).collect();
+        let nuclei = self.find_nuclei(&phonemes);
+
+        let mut syllables = Vec::with_capacity(nuclei.len());
+        let mut onset_start = 0;
+
+        for (idx, nucleus) in nuclei.iter().enumerate() {
+            let coda_end = match nuclei.get(idx + 1) {
+                // Maximal Onset: the earliest split whose remainder is a legal onset
+                Some(next) => (nucleus.end..=next.start)
+                    .find(|&split| self.is_legal_onset(&names[split..next.start]))
+                    .unwrap_or(next.start),
+                None => tokens.len(),
+            };
+
+            syllables.push(Syllable {
+                onset: cluster(&tokens[onset_start..nucleus.start]),
+                nucleus: PhonemeCluster {
+                    phonemes: tokens[nucleus.clone()].to_vec(),
+                },
+                coda: cluster(&tokens[nucleus.end..coda_end]),
+                suprasegmentals: Vec::new(),
+            });
+            onset_start = coda_end;
+        }
+
+        Ok(syllables)
+    }
+
+    /// Moves coda consonants into the following onset for as long as the
+    /// result is still a legal onset, e.g. `at.ka` → `a.tka` when [tk] is an
+    /// allowed onset cluster. Useful for syllables assembled independently.
+    /// Tokens that are not in the phonology are never moved.
+    pub fn resyllabify(&self, syllables: &mut [Syllable], phonology: &PhonologyConfiguration) {
+        for i in 1..syllables.len() {
+            let (before, after) = syllables.split_at_mut(i);
+            let (previous, next) = (&mut before[i - 1], &mut after[0]);
+
+            while let Some(consonant) = previous.coda.as_ref().and_then(|c| c.phonemes.last()) {
+                let mut onset = vec![consonant.clone()];
+                onset.extend(next.onset.iter().flat_map(|o| o.phonemes.iter().cloned()));
+
+                let names: Vec<Option<&str>> = onset
+                    .iter()
+                    .map(|t| lookup(t, phonology).map(|(name, _)| name))
+                    .collect();
+                if !self.is_legal_onset(&names) {
+                    break;
+                }
+
+                next.onset = cluster(&onset);
+                if let Some(coda) = previous.coda.as_mut() {
+                    coda.phonemes.pop();
+                    if coda.phonemes.is_empty() {
+                        previous.coda = None;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Token ranges of all nuclei, merging allowed diphthongs and triphthongs
+    fn find_nuclei(&self, phonemes: &[Option<(&str, bool)>]) -> Vec<Range<usize>> {
+        let vowel = |i: usize| match phonemes.get(i) {
+            Some(Some((name, true))) => Some(*name),
+            _ => None,
+        };
+
+        let mut nuclei = Vec::new();
+        let mut i = 0;
+        while i < phonemes.len() {
+            let Some(first) = vowel(i) else {
+                i += 1;
+                continue;
+            };
+
+            let len = match (vowel(i + 1), vowel(i + 2)) {
+                (Some(second), Some(third)) if self.is_triphthong(first, second, third) => 3,
+                (Some(second), _) if self.is_diphthong(first, second) => 2,
+                _ => 1,
+            };
+
+            nuclei.push(i..i + len);
+            i += len;
+        }
+        nuclei
+    }
+
+    fn is_diphthong(&self, first: &str, second: &str) -> bool {
+        self.nucleus
+            .allowed_diphthongs
+            .iter()
+            .any(|d| d.first == first && d.second == second)
+    }
+
+    fn is_triphthong(&self, first: &str, second: &str, third: &str) -> bool {
+        self.nucleus
+            .allowed_triphthongs
+            .iter()
+            .any(|t| t.first == first && t.second == second && t.third == third)
+    }
+
+    /// Whether the consonant names form an allowed onset; the empty onset always is
+    fn is_legal_onset(&self, names: &[Option<&str>]) -> bool {
+        let Some(names) = names.iter().copied().collect::<Option<Vec<&str>>>() else {
+            return false;
+        };
+
+        match names.as_slice() {
+            [] => true,
+            [single] => {
+                self.onset
+                    .allowed_phonemes
+                    .iter()
+                    .any(|p| p.phoneme == *single)
+                    || self
+                        .onset
+                        .allowed_clusters
+                        .iter()
+                        .any(|c| c.phonemes == [*single])
+            }
+            _ => self
+                .onset
+                .allowed_clusters
+                .iter()
+                .any(|c| c.phonemes == names),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::phonology::consonants::pulmonics::{K_VELAR, T_ALVEOLAR};
+    use crate::phonology::phonemes::{AllowedCluster, AllowedPhoneme};
+    use crate::phonology::A;
+
+    fn phonology() -> PhonologyConfiguration {
+        PhonologyConfiguration::new()
+            .add_consonants(vec![&T_ALVEOLAR, &K_VELAR])
+            .unwrap()
+            .add_vowels(vec![&A])
+            .unwrap()
+    }
+
+    fn render(syllables: &[Syllable]) -> String {
+        syllables
+            .iter()
+            .map(|s| {
+                s.onset
+                    .iter()
+                    .chain(std::iter::once(&s.nucleus))
+                    .chain(s.coda.iter())
+                    .flat_map(|c| c.phonemes.iter().map(String::as_str))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join(".")
+    }
+
+    fn tokens(ipa: &[&str]) -> Vec<String> {
+        ipa.iter().map(|t| t.to_string()).collect()
+    }
+
+    fn single(name: &str) -> AllowedPhoneme {
+        AllowedPhoneme {
+            phoneme: name.to_string(),
+            weight: 1.0,
+        }
+    }
+
+    #[test]
+    fn test_maximal_onset_respects_onset_clusters() {
+        let word = tokens(&["a", "t", "k", "a"]);
+
+        let without_cluster = SyllableConfiguration::new()
+            .set_onset(
+                vec![single("T_ALVEOLAR"), single("K_VELAR")],
+                vec![],
+                vec![],
+            )
+            .unwrap();
+        assert_eq!(
+            render(&without_cluster.syllabify(&word, &phonology()).unwrap()),
+            "at.ka"
+        );
+
+        let with_cluster = SyllableConfiguration::new()
+            .set_onset(
+                vec![single("T_ALVEOLAR"), single("K_VELAR")],
+                vec![AllowedCluster {
+                    phonemes: vec!["T_ALVEOLAR".to_string(), "K_VELAR".to_string()],
+                    weight: 1.0,
+                }],
+                vec![],
+            )
+            .unwrap();
+        assert_eq!(
+            render(&with_cluster.syllabify(&word, &phonology()).unwrap()),
+            "a.tka"
+        );
+    }
+
+    #[test]
+    fn test_resyllabify_moves_coda_into_legal_onset() {
+        let mut syllables = vec![
+            Syllable {
+                onset: None,
+                nucleus: PhonemeCluster {
+                    phonemes: tokens(&["a"]),
+                },
+                coda: cluster(&tokens(&["t"])),
+                suprasegmentals: Vec::new(),
+            },
+            Syllable {
+                onset: cluster(&tokens(&["k"])),
+                nucleus: PhonemeCluster {
+                    phonemes: tokens(&["a"]),
+                },
+                coda: None,
+                suprasegmentals: Vec::new(),
+            },
+        ];
+
+        let without_cluster = SyllableConfiguration::new()
+            .set_onset(
+                vec![single("T_ALVEOLAR"), single("K_VELAR")],
+                vec![],
+                vec![],
+            )
+            .unwrap();
+        without_cluster.resyllabify(&mut syllables, &phonology());
+        assert_eq!(render(&syllables), "at.ka");
+
+        let with_cluster = SyllableConfiguration::new()
+            .set_onset(
+                vec![single("K_VELAR")],
+                vec![AllowedCluster {
+                    phonemes: vec!["T_ALVEOLAR".to_string(), "K_VELAR".to_string()],
+                    weight: 1.0,
+                }],
+                vec![],
+            )
+            .unwrap();
+        with_cluster.resyllabify(&mut syllables, &phonology());
+        assert_eq!(render(&syllables), "a.tka");
+        assert!(syllables[0].coda.is_none());
+    }
+
+    #[test]
+    fn test_word_edges_and_hiatus() {
+        let config = SyllableConfiguration::new()
+            .set_onset(vec![single("K_VELAR")], vec![], vec![])
+            .unwrap();
+
+        let syllables = config
+            .syllabify(&tokens(&["t", "k", "a", "a", "t"]), &phonology())
+            .unwrap();
+        assert_eq!(render(&syllables), "tka.at");
+        assert!(config
+            .syllabify(&tokens(&["t", "k"]), &phonology())
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_unknown_tokens_are_reported() {
+        let config = SyllableConfiguration::new()
+            .set_onset(vec![single("T_ALVEOLAR")], vec![], vec![])
+            .unwrap();
+
+        // Length marks do not change the phoneme
+        let syllables = config
+            .syllabify(&tokens(&["t", "aː"]), &phonology())
+            .unwrap();
+        assert_eq!(render(&syllables), "taː");
+
+        // Aspirated and affricate tokens are not silently read as [t]
+        let errors = config
+            .syllabify(&tokens(&["tʰ", "a", "t\u{0361}ʃ"]), &phonology())
+            .unwrap_err();
+        assert!(errors.has_error("token_0"));
+        assert!(errors.has_error("token_2"));
+        assert!(!errors.has_error("token_1"));
+        assert_eq!(errors.filter_by_code("phoneme_not_found").len(), 2);
+    }
+}
@@ -0,0 +1,96 @@
+//! Tone assignment for tonal languages
+
+use crate::phonology::{Suprasegmental, ToneLevel};
+use crate::syllables::Syllable;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+/// A tone contour a single syllable can carry, e.g. `[Mid, High]` for a rising tone (˧˥)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ToneMelody {
+    pub levels: Vec<ToneLevel>,
+}
+
+impl ToneMelody {
+    pub fn new(levels: Vec<ToneLevel>) -> Self {
+        Self { levels }
+    }
+}
+
+/// The tone melodies a language allows on its syllables
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct TonePattern {
+    pub melodies: Vec<ToneMelody>,
+}
+
+impl TonePattern {
+    pub fn new(melodies: Vec<ToneMelody>) -> Self {
+        Self { melodies }
+    }
+
+    /// Gives every syllable a melody drawn from the inventory, replacing any
+    /// tone it already carried. An empty inventory leaves the syllables untouched.
+    pub fn assign<R: Rng + ?Sized>(&self, syllables: &mut [Syllable], rng: &mut R) {
+        if self.melodies.is_empty() {
+            return;
+        }
+
+        for syllable in syllables {
+            let melody = &self.melodies[rng.gen_range(0..self.melodies.len())];
+            syllable
+                .suprasegmentals
+                .retain(|mark| !matches!(mark, Suprasegmental::Tone(_)));
+            syllable.suprasegmentals.extend(
+                melody
+                    .levels
+                    .iter()
+                    .map(|level| Suprasegmental::Tone(*level)),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::phonology::phonemes::PhonemeCluster;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn syllable() -> Syllable {
+        Syllable {
+            onset: Some(PhonemeCluster {
+                phonemes: vec!["m".to_string()],
+            }),
+            nucleus: PhonemeCluster {
+                phonemes: vec!["a".to_string()],
+            },
+            coda: None,
+            suprasegmentals: vec![Suprasegmental::Tone(ToneLevel::ExtraLow)],
+        }
+    }
+
+    #[test]
+    fn test_assigned_tones_come_from_melodies() {
+        let high = ToneMelody::new(vec![ToneLevel::High]);
+        let rising = ToneMelody::new(vec![ToneLevel::Mid, ToneLevel::High]);
+        let pattern = TonePattern::new(vec![high.clone(), rising.clone()]);
+
+        let mut rng = ChaCha8Rng::seed_from_u64(7);
+        let mut syllables = vec![syllable(); 20];
+        pattern.assign(&mut syllables, &mut rng);
+
+        for syllable in &syllables {
+            let levels: Vec<ToneLevel> = syllable
+                .suprasegmentals
+                .iter()
+                .filter_map(|mark| match mark {
+                    Suprasegmental::Tone(level) => Some(*level),
+                    _ => None,
+                })
+                .collect();
+            assert!(levels == high.levels || levels == rising.levels);
+            assert!(["ma˦", "ma˧˦"].contains(&syllable.to_ipa().as_str()));
+        }
+    }
+}
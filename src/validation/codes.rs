@@ -0,0 +1,201 @@
+//! Typed error codes
+//!
+//! `ValidationError::code` stays a string so new codes can be added without
+//! touching this file, but downstream code can match on `ErrorCode` instead
+//! of comparing string literals.
+
+use std::fmt;
+
+/// Suffixes appended by the suggestion helpers in `syllables::errors::utilities`
+const SUGGESTION_SUFFIXES: &[&str] = &["_with_suggestions", "_no_suggestions"];
+/// Infixes appended by the diphthong/triphthong validators
+const CLUSTER_SUFFIXES: &[&str] = &["_in_diphthong", "_in_triphthong"];
+
+macro_rules! define_error_codes {
+    ($($variant:ident => $code:literal,)*) => {
+        /// Known validation error codes
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        #[non_exhaustive]
+        pub enum ErrorCode {
+            $($variant,)*
+            /// Any code not listed above
+            Other(String),
+        }
+
+        impl ErrorCode {
+            /// The canonical string form of this code
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(ErrorCode::$variant => $code,)*
+                    ErrorCode::Other(code) => code,
+                }
+            }
+
+            fn from_exact(code: &str) -> Option<Self> {
+                match code {
+                    $($code => Some(ErrorCode::$variant),)*
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+define_error_codes! {
+    // Anatomy
+    AnatomicalConfigurationError => "anatomical_configuration_error",
+    VelicPortRequiresSoftPalate => "velic_port_requires_soft_palate",
+    UvulaRequiresSoftPalate => "uvula_requires_soft_palate",
+    SoftPalateRequiresHardPalate => "soft_palate_requires_hard_palate",
+    AlveolarRidgeUnusableWithoutTongueFront => "alveolar_ridge_unusable_without_tongue_front",
+    FlexibleLipsWithoutTeethLimitsSounds => "flexible_lips_without_teeth_limits_sounds",
+    FangsMayInterfereWithInterdentals => "fangs_may_interfere_with_interdentals",
+    EjectivesRequireLarynxControl => "ejectives_require_larynx_control",
+    ClicksWithoutPulmonicLimitsCombinations => "clicks_without_pulmonic_limits_combinations",
+    HumanTeethWithoutLipsUnusual => "human_teeth_without_lips_unusual",
+    InsufficientArticulators => "insufficient_articulators",
+    AlveolarRidgeFunctionallyUnused => "alveolar_ridge_functionally_unused",
+    PhonemeAnatomicallyImpossible => "phoneme_anatomically_impossible",
+    AnatomyJsonParseError => "anatomy_json_parse_error",
+
+    // Phonology
+    UnknownConsonant => "unknown_consonant",
+    UnknownConsonantFromPhonology => "unknown_consonant_from_phonology",
+    UnknownVowel => "unknown_vowel",
+    UnknownPhoneme => "unknown_phoneme",
+    InvalidDiacritic => "invalid_diacritic",
+    InvalidPhonemeConfiguration => "invalid_phoneme_configuration",
+    PhonemeNotFound => "phoneme_not_found",
+    PhonemeAlreadyExists => "phoneme_already_exists",
+    InvalidPhonemeCategory => "invalid_phoneme_category",
+    EmptyPhonemeRegistry => "empty_phoneme_registry",
+    PhonemeJsonParseError => "phoneme_json_parse_error",
+    PhonemeLuaParseError => "phoneme_lua_parse_error",
+    PhonemeFormatDetectionError => "phoneme_format_detection_error",
+    InvalidPhonemeName => "invalid_phoneme_name",
+    DuplicateVowel => "duplicate_vowel",
+    DuplicateConsonant => "duplicate_consonant",
+    PhonologyNotEnoughVowels => "phonology_not_enough_vowels",
+
+    // Syllable configuration
+    EmptyOnset => "empty_onset",
+    EmptyNucleus => "empty_nucleus",
+    UnknownConsonantInCluster => "unknown_consonant_in_cluster",
+    UnknownConsonantInWordInitialOnly => "unknown_consonant_in_word_initial_only",
+    UnknownConsonantInWordFinalOnly => "unknown_consonant_in_word_final_only",
+    UnknownVowelInWordInitialOnly => "unknown_vowel_in_word_initial_only",
+    UnknownVowelInWordFinalOnly => "unknown_vowel_in_word_final_only",
+    UnknownDiphthong => "unknown_diphthong",
+    UnknownTriphthong => "unknown_triphthong",
+    ConsonantNotInPhonology => "consonant_not_in_phonology",
+    ConsonantInClusterNotInPhonology => "consonant_in_cluster_not_in_phonology",
+    ConsonantInWordInitialNotInPhonology => "consonant_in_word_initial_not_in_phonology",
+    ConsonantInWordFinalNotInPhonology => "consonant_in_word_final_not_in_phonology",
+    VowelNotInPhonology => "vowel_not_in_phonology",
+    VowelInWordInitialNotInPhonology => "vowel_in_word_initial_not_in_phonology",
+    VowelInWordFinalNotInPhonology => "vowel_in_word_final_not_in_phonology",
+    DiphthongNotInPhonology => "diphthong_not_in_phonology",
+    TriphthongNotInPhonology => "triphthong_not_in_phonology",
+
+    // Syllable patterns
+    EmptyPattern => "empty_pattern",
+    EmptyGroup => "empty_group",
+    MismatchedParentheses => "mismatched_parentheses",
+    InvalidCharacter => "invalid_character",
+    InvalidPhoneme => "invalid_phoneme",
+    MultipleNucleus => "multiple_nucleus",
+    NoNucleus => "no_nucleus",
+    UnusablePatternWeights => "unusable_pattern_weights",
+    UnreachableCluster => "unreachable_cluster",
+    InvalidWeight => "invalid_weight",
+
+    // General
+    GenericError => "generic_error",
+}
+
+impl ErrorCode {
+    /// Parses a string code, folding the `_with_suggestions` / `_no_suggestions`
+    /// and `_in_diphthong` / `_in_triphthong` variants into their base code.
+    pub fn parse(code: &str) -> Self {
+        if let Some(known) = Self::from_exact(code) {
+            return known;
+        }
+
+        let mut base = code;
+        for suffix in SUGGESTION_SUFFIXES {
+            if let Some(stripped) = base.strip_suffix(suffix) {
+                base = stripped;
+                break;
+            }
+        }
+        for suffix in CLUSTER_SUFFIXES {
+            if let Some(stripped) = base.strip_suffix(suffix) {
+                base = stripped;
+                break;
+            }
+        }
+
+        Self::from_exact(base).unwrap_or_else(|| ErrorCode::Other(code.to_string()))
+    }
+}
+
+impl From<&str> for ErrorCode {
+    fn from(code: &str) -> Self {
+        ErrorCode::parse(code)
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validation::ValidationError;
+
+    #[test]
+    fn test_literal_codes_map_to_variants() {
+        assert_eq!(
+            ValidationError::new("velic_port_requires_soft_palate").code_enum(),
+            ErrorCode::VelicPortRequiresSoftPalate
+        );
+        assert_eq!(
+            ValidationError::new("unknown_vowel").code_enum(),
+            ErrorCode::UnknownVowel
+        );
+        assert_eq!(
+            ValidationError::new("empty_nucleus").code_enum(),
+            ErrorCode::EmptyNucleus
+        );
+        assert_eq!(
+            ValidationError::new("phoneme_anatomically_impossible").code_enum(),
+            ErrorCode::PhonemeAnatomicallyImpossible
+        );
+    }
+
+    #[test]
+    fn test_suggestion_suffixes_fold_into_base_code() {
+        assert_eq!(
+            ErrorCode::parse("unknown_vowel_with_suggestions"),
+            ErrorCode::UnknownVowel
+        );
+        assert_eq!(
+            ErrorCode::parse("unknown_phoneme_no_suggestions"),
+            ErrorCode::UnknownPhoneme
+        );
+        assert_eq!(
+            ErrorCode::parse("unknown_diphthong_in_diphthong_with_suggestions"),
+            ErrorCode::UnknownDiphthong
+        );
+    }
+
+    #[test]
+    fn test_unknown_code_falls_back_to_other() {
+        let code = ErrorCode::parse("something_new");
+        assert_eq!(code, ErrorCode::Other("something_new".to_string()));
+        assert_eq!(code.as_str(), "something_new");
+    }
+}
@@ -1,17 +1,19 @@
+use super::codes::ErrorCode;
+use super::locale::{Locale, MessageCatalog};
 use colored::*;
 use indexmap::IndexMap;
+use serde::Serialize;
 use serde_json::Value;
 use std::borrow::Cow;
-use std::collections::HashMap;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ValidationError {
     /// Error code for programmatic handling
     pub code: Cow<'static, str>,
     /// Human-readable message (optional, can be generated from code + params)
     pub message: Option<Cow<'static, str>>,
-    /// Structured parameters for error context
-    pub params: HashMap<Cow<'static, str>, Value>,
+    /// Structured parameters for error context, in insertion order
+    pub params: IndexMap<Cow<'static, str>, Value>,
 }
 
 impl ValidationError {
@@ -19,7 +21,7 @@ impl ValidationError {
         Self {
             code: code.into(),
             message: None,
-            params: HashMap::new(),
+            params: IndexMap::new(),
         }
     }
 
@@ -32,12 +34,24 @@ impl ValidationError {
         self.message = Some(message.into());
         self
     }
+
+    /// Same code and params; the message is ignored since it is derived from both
+    pub fn is_same(&self, other: &ValidationError) -> bool {
+        self.code == other.code && self.params == other.params
+    }
+
+    /// Typed view of `code`; unknown codes come back as `ErrorCode::Other`
+    pub fn code_enum(&self) -> ErrorCode {
+        ErrorCode::parse(&self.code)
+    }
 }
 
-#[derive(Debug, Clone, Default)]
+/// Serializes as a JSON object keyed by field, in insertion order
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct ValidationErrors(IndexMap<Cow<'static, str>, ValidationErrorsKind>);
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
 pub enum ValidationErrorsKind {
     /// Errors for the struct itself
     Struct(Vec<ValidationError>),
@@ -86,7 +100,12 @@ impl ValidationErrors {
                             ValidationErrorsKind::Struct(existing),
                             ValidationErrorsKind::Struct(new),
                         ) => {
-                            existing.extend(new.clone());
+                            // Gleiche Fehler aus mehreren Validierungsdurchläufen nur einmal
+                            for error in new {
+                                if !existing.iter().any(|e| e.is_same(error)) {
+                                    existing.push(error.clone());
+                                }
+                            }
                         }
                         (
                             ValidationErrorsKind::Field(existing),
@@ -123,50 +142,173 @@ impl ValidationErrors {
         self.0.len()
     }
 
+    /// Number of individual errors in the whole tree, unlike `len` which
+    /// only counts top-level fields
+    pub fn total_count(&self) -> usize {
+        self.0
+            .values()
+            .map(|kind| match kind {
+                ValidationErrorsKind::Struct(errors) => errors.len(),
+                ValidationErrorsKind::Field(nested) => nested.total_count(),
+            })
+            .sum()
+    }
+
     pub fn iter(&self) -> impl Iterator<Item = (&str, &ValidationErrorsKind)> {
         self.0.iter().map(|(k, v)| (k.as_ref(), v))
     }
 
+    /// All errors with the dotted path of their field, e.g. `nucleus.allowed_diphthongs`
+    pub fn flatten(&self) -> Vec<(String, &ValidationError)> {
+        let mut leaves = Vec::new();
+        self.flatten_into("", &mut leaves);
+        leaves
+    }
+
+    /// All errors in the tree whose code is exactly `code`
+    pub fn filter_by_code(&self, code: &str) -> Vec<&ValidationError> {
+        self.flatten()
+            .into_iter()
+            .map(|(_, error)| error)
+            .filter(|error| error.code == code)
+            .collect()
+    }
+
+    fn flatten_into<'a>(&'a self, prefix: &str, leaves: &mut Vec<(String, &'a ValidationError)>) {
+        for (field, kind) in &self.0 {
+            let path = if prefix.is_empty() {
+                field.to_string()
+            } else {
+                format!("{}.{}", prefix, field)
+            };
+            match kind {
+                ValidationErrorsKind::Struct(errors) => {
+                    leaves.extend(errors.iter().map(|error| (path.clone(), error)));
+                }
+                ValidationErrorsKind::Field(nested) => nested.flatten_into(&path, leaves),
+            }
+        }
+    }
+
+    /// Re-renders all messages in `locale` using the built-in catalog
+    pub fn localized(&self, locale: Locale) -> ValidationErrors {
+        self.localized_with(&MessageCatalog::default(), &locale)
+    }
+
+    /// Re-renders all messages from `catalog`; errors without a usable
+    /// template keep their original message
+    pub fn localized_with(&self, catalog: &MessageCatalog, locale: &Locale) -> ValidationErrors {
+        let mut localized = self.clone();
+        for kind in localized.0.values_mut() {
+            match kind {
+                ValidationErrorsKind::Struct(errors) => {
+                    for error in errors {
+                        if let Some(message) = catalog.render(error, locale) {
+                            error.message = Some(message.into());
+                        }
+                    }
+                }
+                ValidationErrorsKind::Field(nested) => {
+                    *nested = nested.localized_with(catalog, locale);
+                }
+            }
+        }
+        localized
+    }
+
+    /// Structured JSON form of the error tree, e.g. for web APIs or log pipelines
+    pub fn to_json(&self) -> serde_json::Result<Value> {
+        serde_json::to_value(self)
+    }
+
+    /// Same tree as `Display`, but without ANSI color codes (e.g. for log files)
+    pub fn display_plain(&self) -> String {
+        PlainValidationErrors(self).to_string()
+    }
+
+    /// Header, Fehlerbaum und Footer; `colors = false` lässt alle ANSI-Codes weg
+    fn fmt_tree(&self, f: &mut std::fmt::Formatter<'_>, colors: bool) -> std::fmt::Result {
+        if self.is_empty() {
+            return Ok(());
+        }
+        // --- Header ---
+        writeln!(
+            f,
+            "\n{}",
+            paint(
+                "══════════════ Validation Errors ══════════════",
+                colors,
+                |s| s.red().bold()
+            )
+        )?;
+        writeln!(
+            f,
+            "{}",
+            paint("There are some errors:", colors, |s| s.bright_black())
+        )?;
+        writeln!(f)?; // Leere Zeile für Abstand
+
+        // --- Fehlerbaum ---
+        self.fmt_recursive(f, "", colors)?;
+        // --- Footer ---
+        writeln!(
+            f,
+            "{}",
+            paint(
+                "══════════════════════════════════════════════════",
+                colors,
+                |s| s.red().bold()
+            )
+        )
+        // Die schöne Baumansicht oben enthält bereits alle Informationen
+    }
+
     /// Rekursive Hilfsfunktion zur formatierten Ausgabe der Fehler
-    fn fmt_recursive(&self, f: &mut std::fmt::Formatter<'_>, prefix: &str) -> std::fmt::Result {
+    fn fmt_recursive(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        prefix: &str,
+        colors: bool,
+    ) -> std::fmt::Result {
         // Peekable, um das letzte Element zu erkennen und die Baumstruktur korrekt zu zeichnen
         let mut iter = self.0.iter().peekable();
         while let Some((field, kind)) = iter.next() {
             let is_last = iter.peek().is_none();
 
             // Baum-Zeichen: '├─' für Elemente in der Mitte, '└─' für das letzte Element
-            let branch = if is_last {
-                "└─".cyan()
-            } else {
-                "├─".cyan()
-            };
+            let branch = paint(if is_last { "└─" } else { "├─" }, colors, |s| {
+                s.cyan()
+            });
             // Präfix für die nächste Ebene: '│  ' wenn es weitergeht, '   ' wenn dies der letzte Zweig war
             let new_prefix = if is_last {
                 format!("{}   ", prefix)
             } else {
-                format!("{}{}  ", prefix, "│".cyan())
+                format!("{}{}  ", prefix, paint("│", colors, |s| s.cyan()))
             };
 
             // Feldnamen in Gelb und Fett hervorheben
-            writeln!(f, "{}{} {}", prefix, branch, field.yellow().bold())?;
+            writeln!(
+                f,
+                "{}{} {}",
+                prefix,
+                branch,
+                paint(field, colors, |s| s.yellow().bold())
+            )?;
 
             match kind {
                 ValidationErrorsKind::Struct(errors) => {
                     let mut error_iter = errors.iter().peekable();
                     while let Some(error) = error_iter.next() {
                         let is_last_error = error_iter.peek().is_none();
-                        let error_branch = if is_last_error {
-                            "└─".red()
-                        } else {
-                            "├─".red()
-                        };
+                        let error_branch =
+                            paint(if is_last_error { "└─" } else { "├─" }, colors, |s| s.red());
                         // Die eigentliche Fehlermeldung ausgeben, etwas eingerückt
                         writeln!(f, "{}{} {}", new_prefix, error_branch, error)?;
                     }
                 }
                 ValidationErrorsKind::Field(nested_errors) => {
                     // Rekursiver Aufruf für verschachtelte Fehler
-                    nested_errors.fmt_recursive(f, &new_prefix)?;
+                    nested_errors.fmt_recursive(f, &new_prefix, colors)?;
                 }
             }
         }
@@ -187,31 +329,25 @@ impl std::fmt::Display for ValidationError {
 
 impl std::fmt::Display for ValidationErrors {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if self.is_empty() {
-            return Ok(());
-        }
-        // --- Header ---
-        writeln!(
-            f,
-            "\n{}",
-            "══════════════ Validation Errors ══════════════"
-                .red()
-                .bold()
-        )?;
-        writeln!(f, "{}", "There are some errors:".bright_black())?;
-        writeln!(f, "")?; // Leere Zeile für Abstand
+        // Farben folgen der globalen Einstellung von `colored`
+        self.fmt_tree(f, true)
+    }
+}
 
-        // --- Fehlerbaum ---
-        self.fmt_recursive(f, "")?;
-        // --- Footer ---
-        writeln!(
-            f,
-            "{}",
-            "══════════════════════════════════════════════════"
-                .red()
-                .bold()
-        )
-        // Die schöne Baumansicht oben enthält bereits alle Informationen
+/// Display adapter behind `ValidationErrors::display_plain`
+struct PlainValidationErrors<'a>(&'a ValidationErrors);
+
+impl std::fmt::Display for PlainValidationErrors<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt_tree(f, false)
+    }
+}
+
+fn paint(text: &str, colors: bool, style: impl Fn(&str) -> ColoredString) -> String {
+    if colors {
+        style(text).to_string()
+    } else {
+        text.to_string()
     }
 }
 
@@ -311,4 +447,163 @@ mod tests {
         assert!(errors1.has_error("field2"));
         assert_eq!(errors1.len(), 2);
     }
+
+    #[test]
+    fn test_total_count_is_recursive() {
+        let mut nested = ValidationErrors::new();
+        nested.add("first", ValidationError::new("error1"));
+        nested.add("first", ValidationError::new("error2"));
+        nested.add("second", ValidationError::new("error3"));
+
+        let mut errors = ValidationErrors::new();
+        errors.add("onset", ValidationError::new("error4"));
+        errors.add_nested("nucleus", nested);
+
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors.total_count(), 4);
+    }
+
+    #[test]
+    fn test_flatten_builds_dotted_paths() {
+        let mut diphthongs = ValidationErrors::new();
+        diphthongs.add("first", ValidationError::new("unknown_vowel"));
+
+        let mut nucleus = ValidationErrors::new();
+        nucleus.add_nested("allowed_diphthongs", diphthongs);
+
+        let mut errors = ValidationErrors::new();
+        errors.add("onset", ValidationError::new("empty_onset"));
+        errors.add_nested("nucleus", nucleus);
+
+        let paths: Vec<(String, &str)> = errors
+            .flatten()
+            .into_iter()
+            .map(|(path, error)| (path, error.code.as_ref()))
+            .collect();
+        assert_eq!(
+            paths,
+            vec![
+                ("onset".to_string(), "empty_onset"),
+                (
+                    "nucleus.allowed_diphthongs.first".to_string(),
+                    "unknown_vowel"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_filter_by_code_searches_nested_errors() {
+        let mut nested = ValidationErrors::new();
+        nested.add(
+            "first",
+            ValidationError::new("unknown_vowel").add_param("attempted_phoneme", "q"),
+        );
+        nested.add("second", ValidationError::new("duplicate_vowel"));
+
+        let mut errors = ValidationErrors::new();
+        errors.add(
+            "nucleus",
+            ValidationError::new("unknown_vowel").add_param("attempted_phoneme", "x"),
+        );
+        errors.add_nested("diphthongs", nested);
+
+        let unknown = errors.filter_by_code("unknown_vowel");
+        assert_eq!(unknown.len(), 2);
+        assert!(unknown.iter().all(|e| e.code == "unknown_vowel"));
+        assert!(errors.filter_by_code("empty_nucleus").is_empty());
+    }
+
+    #[test]
+    fn test_display_plain_has_no_escape_sequences() {
+        let mut nested = ValidationErrors::new();
+        nested.add(
+            "first",
+            ValidationError::new("unknown_vowel").with_message("Unknown vowel"),
+        );
+
+        let mut errors = ValidationErrors::new();
+        errors.add_nested("nucleus", nested);
+
+        let plain = errors.display_plain();
+        assert!(!plain.contains('\u{1b}'));
+        assert!(plain.contains("└─ nucleus"));
+        assert!(plain.contains("Unknown vowel"));
+    }
+
+    #[test]
+    fn test_merge_skips_identical_errors() {
+        let single = || {
+            let mut errors = ValidationErrors::new();
+            errors.add(
+                "nucleus",
+                ValidationError::new("unknown_vowel").add_param("attempted_phoneme", "q"),
+            );
+            errors
+        };
+
+        let mut errors = single();
+        errors.merge(single());
+        assert_eq!(errors.total_count(), 1);
+
+        let mut other = ValidationErrors::new();
+        other.add(
+            "nucleus",
+            ValidationError::new("unknown_vowel").add_param("attempted_phoneme", "x"),
+        );
+        errors.merge(other);
+        assert_eq!(errors.total_count(), 2);
+    }
+
+    #[test]
+    fn test_validation_errors_to_json() {
+        let mut nested = ValidationErrors::new();
+        nested.add(
+            "first",
+            ValidationError::new("unknown_vowel")
+                .add_param("attempted_phoneme", "q")
+                .with_message("Unknown vowel"),
+        );
+
+        let mut errors = ValidationErrors::new();
+        errors.add("onset", ValidationError::new("error1"));
+        errors.add_nested("nucleus", nested);
+
+        let json = errors.to_json().unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "onset": [{ "code": "error1", "message": null, "params": {} }],
+                "nucleus": {
+                    "first": [{
+                        "code": "unknown_vowel",
+                        "message": "Unknown vowel",
+                        "params": { "attempted_phoneme": "q" }
+                    }]
+                }
+            })
+        );
+
+        let keys: Vec<&String> = json.as_object().unwrap().keys().collect();
+        assert_eq!(keys, ["onset", "nucleus"]);
+
+        let mut ordered = ValidationErrors::new();
+        ordered.add(
+            "cluster",
+            ValidationError::new("consonant_in_cluster_not_in_phonology")
+                .add_param("phoneme_index", 1)
+                .add_param("cluster_index", 0)
+                .add_param("attempted_phoneme", "q"),
+        );
+        let json = ordered.to_json().unwrap();
+        let params: Vec<&String> = json["cluster"][0]["params"]
+            .as_object()
+            .unwrap()
+            .keys()
+            .collect();
+        assert_eq!(
+            params,
+            ["phoneme_index", "cluster_index", "attempted_phoneme"]
+        );
+    }
 }
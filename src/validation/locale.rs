@@ -0,0 +1,1214 @@
+//! Localized validation messages
+//!
+//! Messages are rendered from an error's `code` and `params`, so the same
+//! `ValidationErrors` can be shown in any registered locale. Templates use
+//! `{param}` placeholders (`{{` and `}}` for literal braces); a template whose
+//! placeholders cannot all be filled is skipped and the error keeps its
+//! original message.
+//!
+//! Codes that cover several distinct failures carry a `reason` param. A
+//! template registered as `code.reason` takes precedence over the one for
+//! the bare code.
+
+use super::codes::ErrorCode;
+use super::errors::ValidationError;
+use serde_json::Value;
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Locale {
+    De,
+    En,
+    /// Any further locale, identified by its tag (e.g. "fr")
+    Custom(Cow<'static, str>),
+}
+
+#[derive(Debug, Clone)]
+pub struct MessageCatalog {
+    templates: HashMap<Locale, HashMap<Cow<'static, str>, Cow<'static, str>>>,
+}
+
+impl Default for MessageCatalog {
+    /// Catalog with the built-in German and English messages
+    fn default() -> Self {
+        let mut catalog = Self::empty();
+        for (code, de, en) in BUILTIN_MESSAGES {
+            catalog.register(Locale::De, *code, *de);
+            catalog.register(Locale::En, *code, *en);
+        }
+        for entry in PHONEME_LOOKUP_MESSAGES {
+            catalog.register_phoneme_lookup(entry);
+        }
+        for (reason, de, en) in ANATOMICAL_IMPOSSIBILITY_REASONS {
+            let code = format!("phoneme_anatomically_impossible.{}", reason);
+            catalog.register(
+                Locale::De,
+                code.clone(),
+                format!(
+                    "Phonem '{{phoneme}}' ist anatomisch unmöglich für diesen Sprecher: {}",
+                    escape_braces(de)
+                ),
+            );
+            catalog.register(
+                Locale::En,
+                code,
+                format!(
+                    "Phoneme '{{phoneme}}' is anatomically impossible for this speaker: {}",
+                    escape_braces(en)
+                ),
+            );
+        }
+        catalog
+    }
+}
+
+impl MessageCatalog {
+    pub fn empty() -> Self {
+        Self {
+            templates: HashMap::new(),
+        }
+    }
+
+    /// Registers (or replaces) the template for `code` in `locale`
+    pub fn register(
+        &mut self,
+        locale: Locale,
+        code: impl Into<Cow<'static, str>>,
+        template: impl Into<Cow<'static, str>>,
+    ) -> &mut Self {
+        self.templates
+            .entry(locale)
+            .or_default()
+            .insert(code.into(), template.into());
+        self
+    }
+
+    /// Registers the `_with_suggestions` / `_no_suggestions` variants built by
+    /// `syllables::errors::utilities::create_phoneme_validation_error`
+    fn register_phoneme_lookup(&mut self, entry: &PhonemeLookupMessage) {
+        let (prefix, de_noun, en_noun, context) = *entry;
+
+        let (de_base, en_base) = match context {
+            Some(context) => (
+                format!(
+                    "{} '{{attempted_phoneme}}' in {} {{cluster_index}} an Position {{phoneme_index}}",
+                    de_noun, context
+                ),
+                format!(
+                    "{} '{{attempted_phoneme}}' in {} {{cluster_index}} at position {{phoneme_index}}",
+                    en_noun, context
+                ),
+            ),
+            None => (
+                format!("{} '{{attempted_phoneme}}'", de_noun),
+                format!("{} '{{attempted_phoneme}}'", en_noun),
+            ),
+        };
+        // Same choice of plural as the German message in the helper
+        let de_plural = if de_noun.contains("Vokal") {
+            "Vokale"
+        } else {
+            "Konsonanten"
+        };
+        let en_plural = if en_noun == "Consonant" {
+            "consonants"
+        } else {
+            "vowels"
+        };
+
+        let with = format!("{}_with_suggestions", prefix);
+        let without = format!("{}_no_suggestions", prefix);
+        self.register(
+            Locale::De,
+            with.clone(),
+            format!("{}. Meinten Sie: {{suggestions}}", de_base),
+        );
+        self.register(
+            Locale::En,
+            with,
+            format!("{}. Did you mean: {{suggestions}}", en_base),
+        );
+        self.register(
+            Locale::De,
+            without.clone(),
+            format!("{}. Keine ähnlichen {} gefunden.", de_base, de_plural),
+        );
+        self.register(
+            Locale::En,
+            without,
+            format!("{}. No similar {} found.", en_base, en_plural),
+        );
+    }
+
+    /// Template for `code`, falling back to the base code without
+    /// suggestion/cluster suffixes (see `ErrorCode::parse`)
+    pub fn template(&self, locale: &Locale, code: &str) -> Option<&str> {
+        let templates = self.templates.get(locale)?;
+        templates
+            .get(code)
+            .or_else(|| templates.get(ErrorCode::parse(code).as_str()))
+            .map(|t| t.as_ref())
+    }
+
+    /// Template for `error`: `code.reason` first, then the code itself
+    fn template_for(&self, locale: &Locale, error: &ValidationError) -> Option<&str> {
+        error
+            .params
+            .get("reason")
+            .and_then(Value::as_str)
+            .and_then(|reason| self.template(locale, &format!("{}.{}", error.code, reason)))
+            .or_else(|| self.template(locale, &error.code))
+    }
+
+    /// Renders the message for `error` in `locale`, if a usable template exists
+    pub fn render(&self, error: &ValidationError, locale: &Locale) -> Option<String> {
+        let template = self.template_for(locale, error)?;
+        let mut rendered = String::with_capacity(template.len());
+        let mut rest = template;
+
+        while let Some(start) = rest.find(['{', '}']) {
+            rendered.push_str(&rest[..start]);
+            rest = &rest[start..];
+
+            if rest.starts_with("{{") || rest.starts_with("}}") {
+                rendered.push_str(&rest[..1]);
+                rest = &rest[2..];
+                continue;
+            }
+            if rest.starts_with('}') {
+                rendered.push('}');
+                rest = &rest[1..];
+                continue;
+            }
+
+            let end = rest.find('}')?;
+            match error.params.get(&rest[1..end])? {
+                Value::String(s) => rendered.push_str(s),
+                other => rendered.push_str(&other.to_string()),
+            }
+            rest = &rest[end + 1..];
+        }
+        rendered.push_str(rest);
+
+        Some(rendered)
+    }
+}
+
+/// (code prefix, German noun, English noun, context) of the phoneme lookup
+/// errors; the context names the list the phoneme was found in
+type PhonemeLookupMessage = (
+    &'static str,
+    &'static str,
+    &'static str,
+    Option<&'static str>,
+);
+
+const PHONEME_LOOKUP_MESSAGES: &[PhonemeLookupMessage] = &[
+    ("unknown_consonant", "Konsonant", "Consonant", None),
+    (
+        "unknown_consonant_in_cluster",
+        "Konsonant",
+        "Consonant",
+        Some("Cluster"),
+    ),
+    (
+        "unknown_consonant_in_word_initial_only",
+        "Konsonant",
+        "Consonant",
+        Some("word_initial_only"),
+    ),
+    (
+        "unknown_consonant_in_word_final_only",
+        "Konsonant",
+        "Consonant",
+        Some("word_final_only"),
+    ),
+    ("consonant_not_in_phonology", "Konsonant", "Consonant", None),
+    (
+        "consonant_in_cluster_not_in_phonology",
+        "Konsonant",
+        "Consonant",
+        Some("Cluster"),
+    ),
+    (
+        "consonant_in_word_initial_not_in_phonology",
+        "Konsonant",
+        "Consonant",
+        Some("word_initial_only"),
+    ),
+    (
+        "consonant_in_word_final_not_in_phonology",
+        "Konsonant",
+        "Consonant",
+        Some("word_final_only"),
+    ),
+    ("unknown_vowel", "Vokal", "Vowel", None),
+    (
+        "unknown_vowel_in_word_initial_only",
+        "Vokal",
+        "Vowel",
+        Some("word_initial_only"),
+    ),
+    (
+        "unknown_vowel_in_word_final_only",
+        "Vokal",
+        "Vowel",
+        Some("word_final_only"),
+    ),
+    (
+        "unknown_diphthong_in_diphthong",
+        "Diphthong",
+        "Diphthong",
+        Some("allowed_diphthongs"),
+    ),
+    (
+        "unknown_triphthong_in_triphthong",
+        "Triphthongs",
+        "Triphthong",
+        Some("allowed_triphthongs"),
+    ),
+    ("vowel_not_in_phonology", "Vokal", "Vowel", None),
+    (
+        "vowel_in_word_initial_not_in_phonology",
+        "Vokal",
+        "Vowel",
+        Some("word_initial_only"),
+    ),
+    (
+        "vowel_in_word_final_not_in_phonology",
+        "Vokal",
+        "Vowel",
+        Some("word_final_only"),
+    ),
+    (
+        "diphthong_not_in_phonology",
+        "Diphthong",
+        "Diphthong",
+        Some("allowed_diphthongs"),
+    ),
+    (
+        "triphthong_not_in_phonology",
+        "Triphthong",
+        "Triphthong",
+        Some("allowed_triphthongs"),
+    ),
+];
+
+/// Escapes literal braces so `text` can be embedded in a template
+fn escape_braces(text: &str) -> String {
+    text.replace('{', "{{").replace('}', "}}")
+}
+
+/// German description of a `phoneme_anatomically_impossible` reason key
+pub(crate) fn anatomical_impossibility_reason(reason: &str) -> Option<&'static str> {
+    ANATOMICAL_IMPOSSIBILITY_REASONS
+        .iter()
+        .find(|(key, _, _)| *key == reason)
+        .map(|(_, de, _)| *de)
+}
+
+/// (code, German, English)
+const BUILTIN_MESSAGES: &[(&str, &str, &str)] = &[
+    // Anatomy
+    (
+        "velic_port_requires_soft_palate",
+        "Kontrollierbare Nasal-/Oralluft-Umschaltung erfordert einen weichen Gaumen (velum)",
+        "A controllable velic port requires a soft palate (velum)",
+    ),
+    (
+        "uvula_requires_soft_palate",
+        "Ein Zäpfchen (uvula) kann anatomisch nur existieren wenn ein weicher Gaumen vorhanden ist",
+        "A uvula can only exist if a soft palate is present",
+    ),
+    (
+        "soft_palate_requires_hard_palate",
+        "Ein weicher Gaumen erfordert normalerweise auch einen harten Gaumen als anatomische Grundlage",
+        "A soft palate normally requires a hard palate as its anatomical base",
+    ),
+    (
+        "anatomical_configuration_error",
+        "Anatomische Konfiguration ungültig: {reason}",
+        "Invalid anatomical configuration: {reason}",
+    ),
+    (
+        "anatomical_configuration_error.retroflex_requires_agile_tongue_tip",
+        "Retroflexe Laute erfordern eine agile Zungenspitze",
+        "Retroflex capability requires agile tongue tip control",
+    ),
+    (
+        "anatomical_configuration_error.lateral_release_requires_tongue_control",
+        "Laterale Freisetzung erfordert Kontrolle über Zungenblatt oder Zungenspitze",
+        "Lateral release requires functional tongue blade or tip control",
+    ),
+    (
+        "anatomical_configuration_error.clicks_require_agile_tongue_control",
+        "Klicklaute erfordern agile Zungenspitze und agilen Zungenkörper",
+        "Click sound production requires agile tongue tip and body control",
+    ),
+    (
+        "alveolar_ridge_unusable_without_tongue_front",
+        "Ein Alveolarfortsatz ist ohne Zungenspitzen- oder Zungenblattkontrolle nicht nutzbar",
+        "An alveolar ridge is unusable without tongue tip or blade control",
+    ),
+    (
+        "flexible_lips_without_teeth_limits_sounds",
+        "Flexible Lippen ohne Zähne schränken labiodentale Laute wie [f] und [v] ein",
+        "Flexible lips without teeth limit labiodental sounds such as [f] and [v]",
+    ),
+    (
+        "fangs_may_interfere_with_interdentals",
+        "Reißzähne können die Produktion interdentaler Laute wie [θ] und [ð] beeinträchtigen",
+        "Fangs may interfere with interdental sounds such as [θ] and [ð]",
+    ),
+    (
+        "ejectives_require_larynx_control",
+        "Ejektive Laute erfordern aktive Kehlkopfkontrolle für Druckaufbau",
+        "Ejectives require active larynx control to build up pressure",
+    ),
+    (
+        "clicks_without_pulmonic_limits_combinations",
+        "Klicklaute ohne pulmonischen Luftstrom begrenzen kombinierte Artikulationen",
+        "Clicks without pulmonic airflow limit combined articulations",
+    ),
+    (
+        "human_teeth_without_lips_unusual",
+        "Menschliche Zahnkonfiguration ohne Lippen ist anatomisch ungewöhnlich",
+        "A human tooth configuration without lips is anatomically unusual",
+    ),
+    (
+        "insufficient_articulators",
+        "Ohne Lippen oder Zungenkontrolle sind praktisch keine Konsonanten möglich",
+        "Without lips or tongue control practically no consonants are possible",
+    ),
+    (
+        "alveolar_ridge_functionally_unused",
+        "Alveolarfortsatz vorhanden aber ohne Zungenkontrolle funktional nicht nutzbar",
+        "Alveolar ridge present but functionally unusable without tongue control",
+    ),
+    (
+        "phoneme_anatomically_impossible",
+        "Phonem '{phoneme}' ist anatomisch unmöglich für diesen Sprecher: {anatomical_reason}",
+        "Phoneme '{phoneme}' is anatomically impossible for this speaker: {anatomical_reason}",
+    ),
+    (
+        "anatomy_json_parse_error",
+        "Anatomie-JSON konnte nicht gelesen werden: {reason}",
+        "Could not parse anatomy JSON: {reason}",
+    ),
+    // Phonology
+    (
+        "unknown_consonant",
+        "Unbekannter Konsonant '{attempted_name}'. Meinten Sie: {suggestions}",
+        "Unknown consonant '{attempted_name}'. Did you mean: {suggestions}",
+    ),
+    (
+        "unknown_consonant_from_phonology",
+        "Der Konsonant '{attempted_name}' ist nicht in der Phonology der Sprache aufgeführt. Meinten Sie: {suggestions}",
+        "Consonant '{attempted_name}' is not listed in the language's phonology. Did you mean: {suggestions}",
+    ),
+    (
+        "unknown_vowel",
+        "Unbekannter Vokal '{attempted_name}'. Meinten Sie: {suggestions}",
+        "Unknown vowel '{attempted_name}'. Did you mean: {suggestions}",
+    ),
+    (
+        "unknown_phoneme_with_suggestions",
+        "Unbekanntes Phonem '{attempted_phoneme}'. Meinten Sie: {suggestions}",
+        "Unknown phoneme '{attempted_phoneme}'. Did you mean: {suggestions}",
+    ),
+    (
+        "unknown_phoneme_no_suggestions",
+        "Unbekanntes Phonem '{attempted_phoneme}'. Keine ähnlichen Phoneme gefunden.",
+        "Unknown phoneme '{attempted_phoneme}'. No similar phonemes found.",
+    ),
+    (
+        "invalid_diacritic",
+        "Ungültiges Diakritikum '{attempted_diacritic}' für '{base_phoneme}'. Verfügbar: {available_diacritics}",
+        "Invalid diacritic '{attempted_diacritic}' for '{base_phoneme}'. Available: {available_diacritics}",
+    ),
+    (
+        "invalid_diacritic.not_secondary_articulation",
+        "{attempted_diacritic} ist keine Sekundärartikulation für '{base_phoneme}'",
+        "{attempted_diacritic} is not a secondary articulation for '{base_phoneme}'",
+    ),
+    (
+        "invalid_diacritic.second_secondary_articulation",
+        "'{base_phoneme}' hat bereits die Sekundärartikulation {existing_diacritic}, {attempted_diacritic} ist nicht möglich",
+        "'{base_phoneme}' already has the secondary articulation {existing_diacritic}, {attempted_diacritic} is not possible",
+    ),
+    (
+        "invalid_phoneme_configuration",
+        "Ungültige Phonem-Konfiguration: {reason}",
+        "Invalid phoneme configuration: {reason}",
+    ),
+    (
+        "phoneme_not_found",
+        "Phonem nicht gefunden: {phoneme}",
+        "Phoneme not found: {phoneme}",
+    ),
+    (
+        "phoneme_already_exists",
+        "Phonem existiert bereits: {phoneme}",
+        "Phoneme already exists: {phoneme}",
+    ),
+    (
+        "invalid_phoneme_category",
+        "Ungültige Phonem-Kategorie: {attempted_category}",
+        "Invalid phoneme category: {attempted_category}",
+    ),
+    (
+        "empty_phoneme_registry",
+        "Phonem-Registry ist leer",
+        "Phoneme registry is empty",
+    ),
+    (
+        "phoneme_json_parse_error",
+        "JSON-Parse-Fehler: {parse_error}",
+        "JSON parse error: {parse_error}",
+    ),
+    (
+        "phoneme_lua_parse_error",
+        "Lua-Parse-Fehler: {parse_error}",
+        "Lua parse error: {parse_error}",
+    ),
+    (
+        "phoneme_format_detection_error",
+        "Format-Erkennung fehlgeschlagen: {detection_error}",
+        "Format detection failed: {detection_error}",
+    ),
+    (
+        "invalid_phoneme_name",
+        "Ungültiger Phonem-Name: {attempted_name}",
+        "Invalid phoneme name: {attempted_name}",
+    ),
+    (
+        "duplicate_vowel",
+        "Doppelter Vokal: {vowel}",
+        "Duplicate vowel: {vowel}",
+    ),
+    (
+        "duplicate_consonant",
+        "Doppelter Konsonant: {consonant}",
+        "Duplicate consonant: {consonant}",
+    ),
+    (
+        "phonology_not_enough_vowels",
+        "Die Phonologie muss mindestens drei Vokale enthalten.",
+        "The phonology must have at least three vowels.",
+    ),
+    // Syllable configuration
+    (
+        "empty_onset",
+        "Der Onset muss mindestens ein Phonem oder Cluster enthalten",
+        "Onset must contain at least one phoneme or cluster",
+    ),
+    (
+        "empty_nucleus",
+        "Der Nukleus muss mindestens ein Phonem oder Cluster enthalten",
+        "Nucleus must contain at least one phoneme or cluster",
+    ),
+    (
+        "consonant_not_in_phonology",
+        "Konsonant '{attempted_phoneme}' ist nicht in der Phonologie enthalten",
+        "Consonant '{attempted_phoneme}' is not part of the phonology",
+    ),
+    (
+        "consonant_in_cluster_not_in_phonology",
+        "Konsonant '{attempted_phoneme}' in Cluster {cluster_index} an Position {phoneme_index} ist nicht in der Phonologie enthalten",
+        "Consonant '{attempted_phoneme}' in cluster {cluster_index} at position {phoneme_index} is not part of the phonology",
+    ),
+    (
+        "vowel_not_in_phonology",
+        "Vokal '{attempted_phoneme}' ist nicht in der Phonologie enthalten",
+        "Vowel '{attempted_phoneme}' is not part of the phonology",
+    ),
+    // Syllable patterns
+    (
+        "empty_pattern",
+        "Das eingegeben Pattern ist leer!",
+        "Pattern cannot be empty",
+    ),
+    (
+        "empty_group",
+        "Leere Gruppe () ist nicht erlaubt an Position {position}:\n{snippet}",
+        "Empty group () not allowed at position {position}:\n{snippet}",
+    ),
+    (
+        "mismatched_parentheses",
+        "Klammern im Pattern sind nicht ausgeglichen an Position {position}:\n{snippet}",
+        "Mismatched parentheses in pattern at position {position}:\n{snippet}",
+    ),
+    (
+        "mismatched_parentheses.nested_group",
+        "Verschachtelte '(' im Pattern an Position {position}:\n{snippet}",
+        "Nested '(' in pattern at position {position}:\n{snippet}",
+    ),
+    (
+        "mismatched_parentheses.unmatched_close",
+        "')' ohne passende '(' im Pattern an Position {position}:\n{snippet}",
+        "Unmatched ')' in pattern at position {position}:\n{snippet}",
+    ),
+    (
+        "mismatched_parentheses.unclosed_group",
+        "Nicht geschlossene '(' im Pattern an Position {position}:\n{snippet}",
+        "Unclosed '(' in pattern at position {position}:\n{snippet}",
+    ),
+    (
+        "mismatched_parentheses.outside_group",
+        "Zeichen außerhalb von Klammern sind im expliziten Modus nicht erlaubt an Position {position}:\n{snippet}",
+        "Characters outside parentheses not allowed in explicit mode at position {position}:\n{snippet}",
+    ),
+    (
+        "invalid_character",
+        "Ungültiges Zeichen '{character}' (nur C, c, V, v erlaubt)",
+        "Invalid character '{character}' (only C, c, V, v allowed)",
+    ),
+    (
+        "invalid_character.unclassified_group",
+        "Ungültiges Zeichen '?' gefunden",
+        "Invalid character '?' found",
+    ),
+    (
+        "invalid_phoneme",
+        "Pattern enthält ein ungültiges Phonem-Symbol: {symbol} an Position {position}:\n{snippet}",
+        "Pattern contains invalid phonemes symbol: {symbol} at position {position}:\n{snippet}",
+    ),
+    (
+        "multiple_nucleus",
+        "Ein Pattern darf nur eine Nukleus-Gruppe enthalten",
+        "Pattern can only have one nucleus group",
+    ),
+    (
+        "no_nucleus",
+        "Das Pattern muss mindestens einen Vokal (V oder v) enthalten",
+        "Pattern must contain at least one vowel (V or v)",
+    ),
+    (
+        "unusable_pattern_weights.weight_out_of_range",
+        "Gewicht {weight} von Pattern '{schema}' liegt nicht in (0.0, 1.0]",
+        "Weight {weight} of pattern '{schema}' is not in (0.0, 1.0]",
+    ),
+    (
+        "unusable_pattern_weights.no_positive_weight",
+        "Kein Pattern hat ein positives Gewicht",
+        "No pattern has a positive weight",
+    ),
+    (
+        "unreachable_cluster",
+        "{position} '{phonemes}' hat Gewicht 0.0 und wird nie gewählt",
+        "{position} '{phonemes}' has weight 0.0 and can never be selected",
+    ),
+    (
+        "invalid_weight",
+        "Gewicht {weight} liegt nicht in 0.0..=1.0",
+        "Weight {weight} is not in 0.0..=1.0",
+    ),
+];
+
+/// Reasons for `phoneme_anatomically_impossible` as (reason key, de, en).
+/// Each is registered as a `phoneme_anatomically_impossible.<key>` template
+/// and the German text doubles as the emitted `anatomical_reason` param.
+pub(crate) const ANATOMICAL_IMPOSSIBILITY_REASONS: &[(&str, &str, &str)] = &[
+    (
+        "pulmonic_requires_airflow",
+        "Pulmonische Laute erfordern airflow.pulmonic ≠ None",
+        "Pulmonic sounds require airflow.pulmonic ≠ None",
+    ),
+    (
+        "clicks_require_click_airflow",
+        "Klicklaute erfordern airflow.can_produce_clicks = true",
+        "Clicks require airflow.can_produce_clicks = true",
+    ),
+    (
+        "clicks_require_agile_tongue",
+        "Klicklaute erfordern agile Zungenspitze UND Zungenkörper",
+        "Clicks require an agile tongue tip AND tongue body",
+    ),
+    (
+        "ejectives_require_larynx",
+        "Ejektive erfordern larynx.can_produce_ejectives = true",
+        "Ejectives require larynx.can_produce_ejectives = true",
+    ),
+    (
+        "bilabial_requires_lips",
+        "Bilabiale Laute [p, b, m, w] erfordern lips ≠ None",
+        "Bilabial sounds [p, b, m, w] require lips ≠ None",
+    ),
+    (
+        "labiodental_requires_flexible_lips",
+        "Labiodentale Laute [f, v] erfordern lips = Flexible",
+        "Labiodental sounds [f, v] require lips = Flexible",
+    ),
+    (
+        "labiodental_requires_teeth",
+        "Labiodentale Laute [f, v] erfordern teeth ∈ {Human, Flat}. Fangs/None → UNMÖGLICH",
+        "Labiodental sounds [f, v] require teeth ∈ {Human, Flat}. Fangs/None → IMPOSSIBLE",
+    ),
+    (
+        "linguolabial_requires_flexible_lips",
+        "Linguolabiale Laute [t̼, d̼] erfordern lips = Flexible",
+        "Linguolabial sounds [t̼, d̼] require lips = Flexible",
+    ),
+    (
+        "linguolabial_requires_agile_tip",
+        "Linguolabiale Laute [t̼, d̼] erfordern tongue.tip = Agile",
+        "Linguolabial sounds [t̼, d̼] require tongue.tip = Agile",
+    ),
+    (
+        "dental_requires_teeth",
+        "Dentale Laute [θ, ð] erfordern teeth ≠ None",
+        "Dental sounds [θ, ð] require teeth ≠ None",
+    ),
+    (
+        "dental_requires_tongue_front",
+        "Dentale Laute [θ, ð] erfordern tongue.tip/blade ≠ None",
+        "Dental sounds [θ, ð] require tongue.tip/blade ≠ None",
+    ),
+    (
+        "alveolar_requires_ridge",
+        "Alveolare Laute [t, d, n, s, z, l, r] erfordern has_alveolar_ridge = true",
+        "Alveolar sounds [t, d, n, s, z, l, r] require has_alveolar_ridge = true",
+    ),
+    (
+        "alveolar_requires_tongue_front",
+        "Alveolare Laute erfordern tongue.tip/blade ≠ None",
+        "Alveolar sounds require tongue.tip/blade ≠ None",
+    ),
+    (
+        "lateral_requires_lateral_release",
+        "Laterale [l] erfordern tongue.can_perform_lateral_release = true",
+        "Laterals [l] require tongue.can_perform_lateral_release = true",
+    ),
+    (
+        "postalveolar_requires_ridge_and_palate",
+        "Postalveolare [ʃ, ʒ, tʃ, dʒ] erfordern has_alveolar_ridge = true UND has_hard_palate = true",
+        "Postalveolars [ʃ, ʒ, tʃ, dʒ] require has_alveolar_ridge = true AND has_hard_palate = true",
+    ),
+    (
+        "postalveolar_requires_agile_blade",
+        "Postalveolare erfordern tongue.blade = Agile",
+        "Postalveolars require tongue.blade = Agile",
+    ),
+    (
+        "retroflex_requires_curl",
+        "Retroflexe [ʈ, ɖ, ɳ, ʂ, ʐ] erfordern tongue.can_curl_for_retroflex = true",
+        "Retroflexes [ʈ, ɖ, ɳ, ʂ, ʐ] require tongue.can_curl_for_retroflex = true",
+    ),
+    (
+        "retroflex_requires_agile_tip",
+        "Retroflexe erfordern tongue.tip = Agile",
+        "Retroflexes require tongue.tip = Agile",
+    ),
+    (
+        "palatal_requires_hard_palate",
+        "Palatale [c, ɟ, ɲ, j] erfordern has_hard_palate = true",
+        "Palatals [c, ɟ, ɲ, j] require has_hard_palate = true",
+    ),
+    (
+        "palatal_requires_agile_body",
+        "Palatale erfordern tongue.body = Agile",
+        "Palatals require tongue.body = Agile",
+    ),
+    (
+        "velar_requires_soft_palate",
+        "Velare [k, g, ŋ, x, ɣ] erfordern has_soft_palate = true",
+        "Velars [k, g, ŋ, x, ɣ] require has_soft_palate = true",
+    ),
+    (
+        "velar_requires_agile_body",
+        "Velare erfordern tongue.body = Agile",
+        "Velars require tongue.body = Agile",
+    ),
+    (
+        "uvular_requires_uvula",
+        "Uvulare [q, ɢ, ɴ, χ, ʁ] erfordern has_uvula = true",
+        "Uvulars [q, ɢ, ɴ, χ, ʁ] require has_uvula = true",
+    ),
+    (
+        "uvular_requires_agile_body",
+        "Uvulare erfordern tongue.body = Agile",
+        "Uvulars require tongue.body = Agile",
+    ),
+    (
+        "voiceless_requires_voicing_control",
+        "Stimmlose Laute erfordern larynx.voicing ≠ None",
+        "Voiceless sounds require larynx.voicing ≠ None",
+    ),
+    (
+        "voiced_requires_voicing_control",
+        "Stimmhafte Laute erfordern larynx.voicing ∈ {Basic, Advanced}",
+        "Voiced sounds require larynx.voicing ∈ {Basic, Advanced}",
+    ),
+    (
+        "aspirated_requires_advanced_voicing",
+        "Aspirierte Laute [pʰ, tʰ, kʰ] erfordern larynx.voicing = Advanced",
+        "Aspirated sounds [pʰ, tʰ, kʰ] require larynx.voicing = Advanced",
+    ),
+    (
+        "aspirated_requires_advanced_pulmonic",
+        "Aspirierte Laute erfordern airflow.pulmonic = Advanced",
+        "Aspirated sounds require airflow.pulmonic = Advanced",
+    ),
+    (
+        "ejective_voicing_requires_larynx",
+        "Ejektive [p', t', k'] erfordern larynx.can_produce_ejectives = true",
+        "Ejectives [p', t', k'] require larynx.can_produce_ejectives = true",
+    ),
+    (
+        "nasal_requires_velic_port",
+        "Nasale [m, n, ŋ, ɲ] erfordern airflow.velic_port = Controllable",
+        "Nasals [m, n, ŋ, ɲ] require airflow.velic_port = Controllable",
+    ),
+    (
+        "nasal_requires_soft_palate",
+        "Nasale erfordern has_soft_palate = true",
+        "Nasals require has_soft_palate = true",
+    ),
+    (
+        "back_vowel_requires_tongue_body",
+        "Hintere Vokale [u, o, ɑ] erfordern tongue.body ≠ None",
+        "Back vowels [u, o, ɑ] require tongue.body ≠ None",
+    ),
+    (
+        "close_vowel_requires_tongue_body",
+        "Geschlossene Vokale [i, ɪ, u] erfordern tongue.body ≠ None",
+        "Close vowels [i, ɪ, u] require tongue.body ≠ None",
+    ),
+    (
+        "rounded_vowel_requires_lips",
+        "Gerundete Vokale [u, o, ɔ] erfordern lips ≠ None",
+        "Rounded vowels [u, o, ɔ] require lips ≠ None",
+    ),
+    (
+        "labialized_requires_lips",
+        "Labialisierte Laute [kʷ, tʷ] erfordern lips ≠ None",
+        "Labialized sounds [kʷ, tʷ] require lips ≠ None",
+    ),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::anatomy::speaker::{
+        LipControl, PulmonicControl, SpeakerAnatomy, TeethConfiguration, TonguePartControl,
+        VelicPortControl, VoicingControl,
+    };
+    use crate::phonology::consonants::pulmonics::T_ALVEOLAR;
+    use crate::phonology::phonemes::{
+        AllowedCluster, AllowedDiphthong, AllowedPhoneme, AllowedTriphthong, PhonemeCluster,
+    };
+    use crate::phonology::vowels::{all_vowel_phonemes, A, U};
+    use crate::phonology::{
+        consonants::all_consonant_phonemes, Diacritics, PhonologyConfiguration, TongueHeight,
+        TonguePosition,
+    };
+    use crate::syllables::coda::CodaConfiguration;
+    use crate::syllables::mutation::VowelMutation;
+    use crate::syllables::nucleus::NucleusConfiguration;
+    use crate::syllables::onset::OnsetConfiguration;
+    use crate::syllables::patterns::SyllablePattern;
+    use crate::syllables::{Syllable, SyllableConfiguration};
+    use crate::validation::ValidationErrors;
+
+    #[test]
+    fn test_empty_nucleus_renders_in_english() {
+        let errors = NucleusConfiguration::default().validate().unwrap_err();
+
+        let german = errors.localized(Locale::De);
+        let english = errors.localized(Locale::En);
+
+        let message = |errors: &crate::validation::ValidationErrors| match errors
+            .get_field_errors("empty_nucleus")
+        {
+            Some(crate::validation::ValidationErrorsKind::Struct(errs)) => {
+                errs[0].message.clone().unwrap().into_owned()
+            }
+            _ => panic!("expected empty_nucleus error"),
+        };
+
+        assert_eq!(
+            message(&english),
+            "Nucleus must contain at least one phoneme or cluster"
+        );
+        assert_eq!(
+            message(&german),
+            "Der Nukleus muss mindestens ein Phonem oder Cluster enthalten"
+        );
+    }
+
+    #[test]
+    fn test_custom_locale_and_missing_params() {
+        let mut catalog = MessageCatalog::default();
+        catalog.register(
+            Locale::Custom("fr".into()),
+            "unknown_vowel",
+            "Voyelle inconnue '{attempted_phoneme}'",
+        );
+
+        let error = ValidationError::new("unknown_vowel_no_suggestions")
+            .add_param("attempted_phoneme", "q")
+            .with_message("Vokal 'q'");
+        assert_eq!(
+            catalog
+                .render(&error, &Locale::Custom("fr".into()))
+                .as_deref(),
+            Some("Voyelle inconnue 'q'")
+        );
+
+        // Missing placeholder params leave the original message in place
+        let bare = ValidationError::new("phoneme_not_found").with_message("Phonem nicht gefunden");
+        assert_eq!(catalog.render(&bare, &Locale::En), None);
+    }
+
+    #[test]
+    fn test_free_text_anatomical_reason_uses_base_template() {
+        let error = SpeakerAnatomy::human().phoneme_anatomically_impossible("ʘ", "Keine Klicks");
+
+        assert!(!error.params.contains_key("reason"));
+        assert_eq!(
+            MessageCatalog::default()
+                .render(&error, &Locale::En)
+                .as_deref(),
+            Some("Phoneme 'ʘ' is anatomically impossible for this speaker: Keine Klicks")
+        );
+    }
+
+    /// Anatomies that together trip every anatomical check at least once
+    fn broken_anatomies() -> Vec<SpeakerAnatomy> {
+        let human = SpeakerAnatomy::human();
+
+        let mut nothing = human.clone();
+        nothing.oral_cavity.teeth = TeethConfiguration::None;
+        nothing.oral_cavity.has_alveolar_ridge = false;
+        nothing.oral_cavity.has_hard_palate = false;
+        nothing.oral_cavity.has_soft_palate = false;
+        nothing.oral_cavity.has_uvula = false;
+        nothing.lips = LipControl::None;
+        nothing.tongue.tip = TonguePartControl::None;
+        nothing.tongue.blade = TonguePartControl::None;
+        nothing.tongue.body = TonguePartControl::None;
+        nothing.tongue.can_curl_for_retroflex = false;
+        nothing.tongue.can_perform_lateral_release = false;
+        nothing.larynx.voicing = VoicingControl::None;
+        nothing.larynx.can_produce_ejectives = false;
+        nothing.airflow.pulmonic = PulmonicControl::None;
+        nothing.airflow.velic_port = VelicPortControl::None;
+        nothing.airflow.can_produce_clicks = false;
+
+        let mut limited = human.clone();
+        limited.oral_cavity.teeth = TeethConfiguration::None;
+        limited.tongue.tip = TonguePartControl::Limited;
+        limited.tongue.blade = TonguePartControl::Limited;
+        limited.tongue.body = TonguePartControl::Limited;
+        limited.tongue.can_perform_lateral_release = false;
+        limited.airflow.pulmonic = PulmonicControl::Basic;
+
+        let mut no_tongue_front = human.clone();
+        no_tongue_front.tongue.tip = TonguePartControl::None;
+        no_tongue_front.tongue.blade = TonguePartControl::None;
+        no_tongue_front.lips = LipControl::None;
+        no_tongue_front.larynx.voicing = VoicingControl::None;
+
+        let mut no_soft_palate = human.clone();
+        no_soft_palate.oral_cavity.has_soft_palate = false;
+
+        let mut fangs = human;
+        fangs.oral_cavity.teeth = TeethConfiguration::Fangs;
+
+        vec![nothing, limited, no_tongue_front, no_soft_palate, fangs]
+    }
+
+    fn anatomy_errors() -> Vec<ValidationError> {
+        let mut errors = Vec::new();
+        let phonology = PhonologyConfiguration::new()
+            .add_consonants(all_consonant_phonemes())
+            .unwrap()
+            .add_vowels(all_vowel_phonemes())
+            .unwrap();
+
+        for anatomy in broken_anatomies() {
+            if let Err(e) = anatomy.validate_anatomical_consistency() {
+                errors.extend(e.flatten().into_iter().map(|(_, e)| e.clone()));
+            }
+            if let Err(e) = phonology.validate_against_anatomy(&anatomy) {
+                errors.extend(e.flatten().into_iter().map(|(_, e)| e.clone()));
+            }
+
+            let checks = [
+                anatomy.validate_airflow_mechanism("p", "pulmonic"),
+                anatomy.validate_airflow_mechanism("ǀ", "click"),
+                anatomy.validate_airflow_mechanism("pʼ", "ejective"),
+                anatomy.validate_labial_articulation("p", "bilabial"),
+                anatomy.validate_labial_articulation("f", "labiodental"),
+                anatomy.validate_labial_articulation("t̼", "linguolabial"),
+                anatomy.validate_coronal_articulation("θ", "dental"),
+                anatomy.validate_coronal_articulation("l", "alveolar"),
+                anatomy.validate_coronal_articulation("ʃ", "postalveolar"),
+                anatomy.validate_coronal_articulation("ʈ", "retroflex"),
+                anatomy.validate_coronal_articulation("c", "palatal"),
+                anatomy.validate_dorsal_articulation("k", "velar"),
+                anatomy.validate_dorsal_articulation("q", "uvular"),
+                anatomy.validate_voicing_requirements("p", "voiceless"),
+                anatomy.validate_voicing_requirements("b", "voiced"),
+                anatomy.validate_voicing_requirements("pʰ", "aspirated"),
+                anatomy.validate_voicing_requirements("pʼ", "ejective"),
+                anatomy.validate_nasality_requirements("m", true),
+                anatomy.validate_vowel_articulation(
+                    "u",
+                    TongueHeight::Close,
+                    TonguePosition::Back,
+                    true,
+                ),
+                anatomy.validate_vowel_articulation(
+                    "i",
+                    TongueHeight::Close,
+                    TonguePosition::Front,
+                    false,
+                ),
+                anatomy.validate_vowel_articulation(
+                    "ɶ",
+                    TongueHeight::Open,
+                    TonguePosition::Front,
+                    true,
+                ),
+                anatomy.validate_secondary_articulation("kʷ", &[Diacritics::Labialized]),
+            ];
+            errors.extend(checks.into_iter().filter_map(Result::err));
+        }
+        errors
+    }
+
+    fn syllable_errors() -> Vec<ValidationError> {
+        let mut collected: Vec<ValidationErrors> = Vec::new();
+        let phoneme = |name: &str| AllowedPhoneme {
+            phoneme: name.to_string(),
+            weight: 1.0,
+        };
+        let cluster = |names: &[&str]| AllowedCluster {
+            phonemes: names.iter().map(|n| n.to_string()).collect(),
+            weight: 1.0,
+        };
+        let phonology = PhonologyConfiguration::new()
+            .add_consonants(vec![&T_ALVEOLAR])
+            .unwrap()
+            .add_vowels(vec![&A])
+            .unwrap();
+
+        // Unknown names, once close to a real name (suggestions) and once not
+        for bad in ["T_ALVEOLARR", "QQQQQQQQQQQQ"] {
+            let mut onset = OnsetConfiguration::new(
+                vec![phoneme(bad)],
+                vec![cluster(&[bad])],
+                vec![cluster(&[bad])],
+            );
+            collected.extend(onset.validate().err());
+            let mut coda = CodaConfiguration::new(
+                vec![phoneme(bad)],
+                vec![cluster(&[bad])],
+                vec![cluster(&[bad])],
+            );
+            collected.extend(coda.validate().err());
+        }
+        for (bad, initial) in [("AA", true), ("QQQQQQQQQQQQ", false)] {
+            let word_list = |wanted: bool| {
+                if wanted {
+                    vec![cluster(&[bad])]
+                } else {
+                    vec![]
+                }
+            };
+            let mut nucleus = NucleusConfiguration::new(
+                vec![phoneme(bad)],
+                vec![AllowedDiphthong {
+                    first: bad.to_string(),
+                    second: "A".to_string(),
+                    weight: 1.0,
+                }],
+                vec![AllowedTriphthong {
+                    first: bad.to_string(),
+                    second: "A".to_string(),
+                    third: "A".to_string(),
+                    weight: 1.0,
+                }],
+                word_list(initial),
+                word_list(!initial),
+            );
+            collected.extend(nucleus.validate().err());
+        }
+
+        // Known names that are missing from the phonology
+        for missing in ["D_ALVEOLAR", "QQQQQQQQQQQQ"] {
+            let onset = OnsetConfiguration::new(
+                vec![phoneme(missing)],
+                vec![cluster(&[missing])],
+                vec![cluster(&[missing])],
+            );
+            collected.extend(
+                onset
+                    .validate_against_phonology_consonants(&phonology)
+                    .err(),
+            );
+            let coda = CodaConfiguration::new(
+                vec![phoneme(missing)],
+                vec![cluster(&[missing])],
+                vec![cluster(&[missing])],
+            );
+            collected.extend(coda.validate_against_phonology_consonants(&phonology).err());
+        }
+        for missing in ["U", "QQQQQQQQQQQQ"] {
+            let nucleus = NucleusConfiguration::new(
+                vec![phoneme(missing)],
+                vec![AllowedDiphthong {
+                    first: missing.to_string(),
+                    second: "A".to_string(),
+                    weight: 1.0,
+                }],
+                vec![AllowedTriphthong {
+                    first: missing.to_string(),
+                    second: "A".to_string(),
+                    third: "A".to_string(),
+                    weight: 1.0,
+                }],
+                vec![cluster(&[missing])],
+                vec![cluster(&[missing])],
+            );
+            collected.extend(nucleus.validate_against_phonology_vowels(&phonology).err());
+        }
+
+        collected.extend(NucleusConfiguration::default().validate().err());
+        collected.extend(OnsetConfiguration::default().validate().err());
+        collected.extend(
+            SyllableConfiguration::new()
+                .set_nucleus(vec![phoneme("A")], vec![], vec![], vec![], vec![])
+                .err(),
+        );
+
+        for schema in [
+            "",
+            "((V)",
+            "V)",
+            "()(V)",
+            "(V",
+            "C(V)",
+            "CXV",
+            "(C)(V)(V)",
+            "(C)",
+            "CC",
+        ] {
+            collected.extend(SyllablePattern::new(schema, 1.0).validate_pattern().err());
+        }
+        let weights = SyllableConfiguration::new()
+            .add_pattern("CV", 1.5)
+            .unwrap()
+            .add_pattern("CVC", -0.5)
+            .unwrap();
+        collected.extend(weights.validate_pattern_weights().err());
+        let unreachable = SyllableConfiguration::new()
+            .set_onset(
+                vec![AllowedPhoneme {
+                    phoneme: "T_ALVEOLAR".to_string(),
+                    weight: 0.0,
+                }],
+                vec![],
+                vec![],
+            )
+            .unwrap();
+        collected.push(unreachable.unreachable_cluster_warnings());
+
+        collected.extend(
+            AllowedCluster::builder()
+                .phoneme("q")
+                .weight(1.5)
+                .build(&phonology)
+                .err(),
+        );
+        collected.extend(PhonologyConfiguration::new().add_vowels(vec![&A, &A]).err());
+        collected.extend(
+            PhonologyConfiguration::new()
+                .add_consonants(vec![&T_ALVEOLAR, &T_ALVEOLAR])
+                .err(),
+        );
+
+        let mut errors: Vec<ValidationError> = collected
+            .iter()
+            .flat_map(|e| e.flatten().into_iter().map(|(_, e)| e.clone()))
+            .collect();
+        errors.extend(
+            T_ALVEOLAR
+                .with_secondary_articulation(Diacritics::Long)
+                .err(),
+        );
+        errors.extend(
+            T_ALVEOLAR
+                .with_secondary_articulation(Diacritics::Palatalized)
+                .and_then(|t| t.with_secondary_articulation(Diacritics::Velarized))
+                .err(),
+        );
+        let mut word = vec![Syllable {
+            onset: None,
+            nucleus: PhonemeCluster {
+                phonemes: vec!["u".to_string()],
+            },
+            coda: None,
+            suprasegmentals: Vec::new(),
+        }];
+        errors.extend(
+            VowelMutation {
+                position: Some(TonguePosition::Front),
+                ..Default::default()
+            }
+            .apply(
+                &mut word,
+                &PhonologyConfiguration::new().add_vowels(vec![&U]).unwrap(),
+            )
+            .err(),
+        );
+        errors
+    }
+
+    #[test]
+    fn test_every_emitted_error_is_localized() {
+        let catalog = MessageCatalog::default();
+        let errors: Vec<ValidationError> = anatomy_errors()
+            .into_iter()
+            .chain(syllable_errors())
+            .collect();
+
+        for error in &errors {
+            let german = catalog.render(error, &Locale::De);
+            let english = catalog.render(error, &Locale::En);
+            let (Some(german), Some(english)) = (german, english) else {
+                panic!("no template for {:?}", error);
+            };
+            assert_ne!(german, english, "English equals German for {:?}", error);
+            if let Some(message) = &error.message {
+                assert!(
+                    *message == german || *message == english,
+                    "template does not reproduce emitted message for {:?}\n  de: {}\n  en: {}",
+                    error,
+                    german,
+                    english
+                );
+            }
+        }
+
+        // Every reason-keyed anatomy template is exercised by the paths above
+        let reasons: Vec<String> = errors
+            .iter()
+            .filter_map(|e| {
+                let reason = e.params.get("reason")?.as_str()?;
+                Some(format!("{}.{}", e.code, reason))
+            })
+            .collect();
+        for (key, _, _) in BUILTIN_MESSAGES {
+            if key.starts_with("anatomical_configuration_error.") {
+                assert!(reasons.iter().any(|r| r == key), "{} never emitted", key);
+            }
+        }
+        for (reason, _, _) in ANATOMICAL_IMPOSSIBILITY_REASONS {
+            let key = format!("phoneme_anatomically_impossible.{}", reason);
+            assert!(reasons.contains(&key), "{} never emitted", key);
+        }
+    }
+}
@@ -1,5 +1,9 @@
+pub mod codes;
 pub mod errors;
+pub mod locale;
 pub mod traits;
 
+pub use codes::ErrorCode;
 pub use errors::{FormattedValidationErrors, ValidationError, ValidationErrors, ValidationErrorsKind};
+pub use locale::{Locale, MessageCatalog};
 pub use traits::{Validate, ValidateWithContext};
\ No newline at end of file
@@ -1,8 +1,20 @@
-use crate::validation::errors::ValidationErrors;
+use crate::validation::errors::{ValidationError, ValidationErrors};
 
 /// Core validation trait for simple validation
 pub trait Validate {
     fn validate(&self) -> Result<(), ValidationErrors>;
+
+    /// Yes/no check for hot paths: only the first error is returned.
+    /// Implementors with expensive checks should override this to stop early.
+    fn validate_fast(&self) -> Result<(), ValidationError> {
+        match self.validate() {
+            Ok(()) => Ok(()),
+            Err(errors) => match errors.flatten().into_iter().next() {
+                Some((_, error)) => Err(error.clone()),
+                None => Ok(()),
+            },
+        }
+    }
 }
 
 /// Context-aware validation trait for validations that require external context
@@ -10,7 +22,7 @@ pub trait ValidateWithContext<C> {
     fn validate_with_context(&self, context: &C) -> Result<(), ValidationErrors>;
 }
 
-/// Domain-specific validation traits
+// Domain-specific validation traits
 
 /// Phoneme validation trait
 pub trait ValidatePhoneme {
@@ -47,7 +59,6 @@ pub trait ValidationSuggestions {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::validation::errors::ValidationError;
 
     struct TestValidatable {
         is_valid: bool,
@@ -76,6 +87,26 @@ mod tests {
         assert!(result.unwrap_err().has_error("test_field"));
     }
 
+    struct SeveralErrors;
+
+    impl Validate for SeveralErrors {
+        fn validate(&self) -> Result<(), ValidationErrors> {
+            let mut errors = ValidationErrors::new();
+            errors.add("first", ValidationError::new("first_error"));
+            errors.add("second", ValidationError::new("second_error"));
+            Err(errors)
+        }
+    }
+
+    #[test]
+    fn test_validate_fast_returns_first_error() {
+        assert_eq!(
+            SeveralErrors.validate_fast().unwrap_err().code,
+            "first_error"
+        );
+        assert!(TestValidatable { is_valid: true }.validate_fast().is_ok());
+    }
+
     struct TestContextValidatable {
         value: i32,
     }